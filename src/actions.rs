@@ -26,6 +26,8 @@ pub enum GameKey {
     Remove,
     Apply,
     Equip,
+    Fire,
+    Cast,
 }
 
 fn key_mapping(key: VirtualKeyCode) -> Option<GameKey> {
@@ -51,6 +53,8 @@ fn key_mapping(key: VirtualKeyCode) -> Option<GameKey> {
         VirtualKeyCode::R => Some(GameKey::Remove),
         VirtualKeyCode::A => Some(GameKey::Apply),
         VirtualKeyCode::E => Some(GameKey::Equip),
+        VirtualKeyCode::F => Some(GameKey::Fire),
+        VirtualKeyCode::Z => Some(GameKey::Cast),
         _ => None,
     }
 }
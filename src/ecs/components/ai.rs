@@ -0,0 +1,50 @@
+use super::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AIState {
+    Idle,
+    Wander,
+    Chase,
+    Search,
+    Flee,
+    Return,
+}
+
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct MonsterAI {
+    pub state: AIState,
+    pub default_state: AIState,
+    pub home: Point,
+    pub last_seen: Option<Point>,
+    pub search_turns: i32,
+    pub turns_searching: i32,
+    pub flee_below: i32, // Percentage of max hp
+}
+
+impl MonsterAI {
+    pub fn new(default_state: AIState, home: Point, search_turns: i32, flee_below: i32) -> Self {
+        MonsterAI {
+            home,
+            flee_below,
+            search_turns,
+            default_state,
+            last_seen: None,
+            turns_searching: 0,
+            state: default_state,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, PartialEq, Eq, ConvertSaveload)]
+pub struct Faction(pub String);
+
+impl Faction {
+    pub fn new<S: ToString>(name: S) -> Self { Faction(name.to_string()) }
+}
+
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct Follower {
+    pub leader: Entity,
+}
+
+impl_new!(Follower, leader: Entity);
@@ -10,8 +10,58 @@ pub struct DefenseBonus {
     pub defense: i32,
 }
 
+/// Damage dealt by a weapon, or by a mob's natural attack when it has no weapon
+#[derive(Component, ConvertSaveload, Clone)]
+pub struct MeleeDamage {
+    pub dice: Dice,
+    pub damage_type: DamageType,
+}
+
+/// A bow, crossbow or thrown weapon. Bows and crossbows use up ammunition of their `ammo` kind
+/// from the wielder's backpack; thrown weapons fly off themselves.
+#[derive(Component, ConvertSaveload, Clone)]
+pub struct RangedWeapon {
+    pub range: i32,
+    pub damage: Dice,
+    pub damage_type: DamageType,
+    pub ammo: Option<String>,
+    pub thrown: bool,
+}
+
+/// Total energy an attack with this weapon costs, in place of `ACTION_COST`
+#[derive(Component, ConvertSaveload, Clone)]
+pub struct AttackCost(pub i32);
+
+/// A mob's own ranged attack, limited by ammo and/or a cooldown between shots
+#[derive(Component, ConvertSaveload, Clone)]
+pub struct RangedAttack {
+    pub range: i32,
+    pub damage: i32,
+    pub damage_type: DamageType,
+    pub glyph: FontCharType,
+    pub ammo: Option<i32>,
+    pub cooldown: i32,
+    pub cooldown_remaining: i32,
+}
+
+impl RangedAttack {
+    pub fn new(
+        range: i32,
+        damage: i32,
+        damage_type: DamageType,
+        glyph: FontCharType,
+        ammo: Option<i32>,
+        cooldown: i32,
+    ) -> Self {
+        Self { range, damage, damage_type, glyph, ammo, cooldown, cooldown_remaining: 0 }
+    }
+
+    pub fn is_ready(&self) -> bool { self.cooldown_remaining <= 0 && self.ammo.is_none_or(|ammo| ammo > 0) }
+}
+
 #[derive(Component, Clone, Debug, ConvertSaveload)]
 pub struct Blood(pub RGB);
 
 impl_new!(DefenseBonus, defense: i32);
 impl_new!(MeleePowerBonus, power: i32);
+impl_new!(MeleeDamage, dice: Dice, damage_type: DamageType);
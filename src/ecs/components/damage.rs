@@ -0,0 +1,90 @@
+use super::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum DamageType {
+    #[default]
+    Physical,
+    Fire,
+    Cold,
+    Poison,
+    Magic,
+}
+
+impl DamageType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DamageType::Physical => "physical",
+            DamageType::Fire => "fire",
+            DamageType::Cold => "cold",
+            DamageType::Poison => "poison",
+            DamageType::Magic => "magic",
+        }
+    }
+
+    pub fn color(&self) -> (u8, u8, u8) {
+        match self {
+            DamageType::Physical => RED,
+            DamageType::Fire => ORANGE,
+            DamageType::Cold => LIGHTBLUE,
+            DamageType::Poison => GREEN,
+            DamageType::Magic => MAGENTA,
+        }
+    }
+}
+
+/// How badly a damage type hurts, once every resistance that applies has been weighed up
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DamageResponse {
+    Normal,
+    /// Half damage (rounded down, but never below 1)
+    Resistant,
+    /// No damage at all
+    Immune,
+    /// Double damage
+    Vulnerable,
+}
+
+impl DamageResponse {
+    pub fn apply(&self, amount: i32) -> i32 {
+        match self {
+            DamageResponse::Normal => amount,
+            DamageResponse::Resistant => i32::max(1, amount / 2),
+            DamageResponse::Immune => 0,
+            DamageResponse::Vulnerable => amount * 2,
+        }
+    }
+}
+
+/// Damage types a mob shrugs off, ignores or suffers extra from. Worn armour carrying this
+/// component protects (or exposes) whoever has it equipped.
+#[derive(Component, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Resistances {
+    pub resist: Vec<DamageType>,
+    pub immune: Vec<DamageType>,
+    pub vulnerable: Vec<DamageType>,
+}
+
+impl Resistances {
+    /// Combines a set of resistances against `damage_type`. Any immunity wins outright; otherwise
+    /// resistances and vulnerabilities cancel each other out one for one.
+    pub fn response<'a>(
+        resistances: impl Iterator<Item = &'a Resistances>,
+        damage_type: DamageType,
+    ) -> DamageResponse {
+        let mut balance = 0;
+        for res in resistances {
+            if res.immune.contains(&damage_type) {
+                return DamageResponse::Immune;
+            }
+
+            balance += res.resist.iter().filter(|t| **t == damage_type).count() as i32;
+            balance -= res.vulnerable.iter().filter(|t| **t == damage_type).count() as i32;
+        }
+
+        match balance {
+            b if b > 0 => DamageResponse::Resistant,
+            b if b < 0 => DamageResponse::Vulnerable,
+            _ => DamageResponse::Normal,
+        }
+    }
+}
@@ -1,9 +1,81 @@
 use super::*;
 
-#[derive(Eq, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize)]
 pub enum EquipmentSlot {
     Melee,
+    Ranged,
     Shield,
+    Head,
+    Torso,
+    Legs,
+    Feet,
+    Hands,
+    Ring1,
+    Ring2,
+    Amulet,
+    Cloak,
+}
+
+impl EquipmentSlot {
+    /// Every slot, in the order they are listed on screen
+    pub const ALL: [EquipmentSlot; 12] = [
+        EquipmentSlot::Melee,
+        EquipmentSlot::Ranged,
+        EquipmentSlot::Shield,
+        EquipmentSlot::Head,
+        EquipmentSlot::Torso,
+        EquipmentSlot::Legs,
+        EquipmentSlot::Feet,
+        EquipmentSlot::Hands,
+        EquipmentSlot::Ring1,
+        EquipmentSlot::Ring2,
+        EquipmentSlot::Amulet,
+        EquipmentSlot::Cloak,
+    ];
+
+    /// Parses a slot name from the raws. A plain "ring" goes on the first hand.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "melee" | "weapon" => Some(EquipmentSlot::Melee),
+            "ranged" => Some(EquipmentSlot::Ranged),
+            "shield" => Some(EquipmentSlot::Shield),
+            "head" => Some(EquipmentSlot::Head),
+            "torso" => Some(EquipmentSlot::Torso),
+            "legs" => Some(EquipmentSlot::Legs),
+            "feet" => Some(EquipmentSlot::Feet),
+            "hands" => Some(EquipmentSlot::Hands),
+            "ring" | "ring1" => Some(EquipmentSlot::Ring1),
+            "ring2" => Some(EquipmentSlot::Ring2),
+            "amulet" => Some(EquipmentSlot::Amulet),
+            "cloak" => Some(EquipmentSlot::Cloak),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EquipmentSlot::Melee => "Weapon",
+            EquipmentSlot::Ranged => "Ranged",
+            EquipmentSlot::Shield => "Shield",
+            EquipmentSlot::Head => "Head",
+            EquipmentSlot::Torso => "Torso",
+            EquipmentSlot::Legs => "Legs",
+            EquipmentSlot::Feet => "Feet",
+            EquipmentSlot::Hands => "Hands",
+            EquipmentSlot::Ring1 => "Ring",
+            EquipmentSlot::Ring2 => "Ring",
+            EquipmentSlot::Amulet => "Amulet",
+            EquipmentSlot::Cloak => "Cloak",
+        }
+    }
+
+    /// Slots an item meant for this one may go in instead, when this one is already taken
+    pub fn alternative(&self) -> Option<EquipmentSlot> {
+        match self {
+            EquipmentSlot::Ring1 => Some(EquipmentSlot::Ring2),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Component, Serialize, Deserialize, Clone)]
@@ -17,5 +89,25 @@ pub struct Equipped {
     pub slot: EquipmentSlot,
 }
 
+/// A weapon that needs both hands, leaving nothing free for a shield
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct TwoHanded {}
+
+/// Can't be taken off once equipped, until the curse is lifted
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Cursed {
+    /// Whether the player has found out about the curse yet
+    pub known: bool,
+}
+
+/// Attribute changes granted to whoever has this item equipped
+#[derive(Component, Serialize, Deserialize, Clone, Default)]
+pub struct AttributeBonus {
+    pub might: Option<i32>,
+    pub fitness: Option<i32>,
+    pub quickness: Option<i32>,
+    pub intelligence: Option<i32>,
+}
+
 impl_new!(Equippable, slot: EquipmentSlot);
 impl_new!(Equipped, owner: Entity, slot: EquipmentSlot);
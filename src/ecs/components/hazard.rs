@@ -0,0 +1,14 @@
+use super::*;
+
+/// Leaves a fire, gas cloud or the like wherever it lands
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct CreatesHazard {
+    pub kind: HazardKind,
+    pub turns: i32,
+}
+
+/// Puts out fires wherever it lands
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Douses {}
+
+impl_new!(CreatesHazard, kind: HazardKind, turns: i32);
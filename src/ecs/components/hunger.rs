@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
 pub enum HungerState {
     WellFed,
     Normal,
@@ -10,12 +10,14 @@ pub enum HungerState {
 
 #[derive(Component, Serialize, Deserialize, Clone)]
 pub struct HungerClock {
-    pub duration: i32,
     pub state: HungerState,
+    pub nutrition: i32,
+    /// Percentage of the configured consumption this eater burns through each turn
+    pub rate: i32,
 }
 
-#[derive(Component, Debug, Serialize, Deserialize, Clone, Default)]
-#[storage(NullStorage)]
-pub struct ProvidesFood {}
+/// How much nutrition eating this provides
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct ProvidesFood(pub i32);
 
-impl_new!(HungerClock, state: HungerState, duration: i32);
+impl_new!(HungerClock, state: HungerState, nutrition: i32, rate: i32);
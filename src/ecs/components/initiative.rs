@@ -0,0 +1,26 @@
+use super::*;
+
+/// Energy an actor must bank before it may take an action
+pub const ACTION_COST: i32 = 100;
+/// Energy gained per tick by an unhasted, unslowed actor
+pub const NORMAL_SPEED: i32 = 10;
+
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct Initiative {
+    pub speed: i32,
+    pub energy: i32,
+}
+
+impl Initiative {
+    pub fn new(speed: i32) -> Self { Initiative { speed, energy: 0 } }
+
+    /// How many ticks must pass before this actor has enough energy to act
+    pub fn ticks_until_ready(&self) -> i32 {
+        let missing = ACTION_COST - self.energy;
+        if missing <= 0 {
+            0
+        } else {
+            (missing + self.speed - 1) / self.speed
+        }
+    }
+}
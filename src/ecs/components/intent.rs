@@ -5,6 +5,11 @@ pub struct WantsToMelee {
     pub target: Entity,
 }
 
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct WantsToShoot {
+    pub target: Entity,
+}
+
 #[derive(Component, Debug, Clone, ConvertSaveload)]
 pub struct WantsToPickupItem {
     pub collected_by: Entity,
@@ -17,6 +22,12 @@ pub struct WantsToUseItem {
     pub target: Option<Point>,
 }
 
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct WantsToCastSpell {
+    pub spell: Entity,
+    pub target: Option<Point>,
+}
+
 #[derive(Component, Debug, Clone, ConvertSaveload)]
 pub struct WantsToDropItem {
     pub item: Entity,
@@ -28,7 +39,9 @@ pub struct WantsToRemoveItem {
 }
 
 impl_new!(WantsToMelee, target: Entity);
+impl_new!(WantsToShoot, target: Entity);
 impl_new!(WantsToDropItem, item: Entity);
 impl_new!(WantsToRemoveItem, item: Entity);
 impl_new!(WantsToUseItem, item: Entity, target: Option<Point>);
+impl_new!(WantsToCastSpell, spell: Entity, target: Option<Point>);
 impl_new!(WantsToPickupItem, item: Entity, collected_by: Entity);
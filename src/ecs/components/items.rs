@@ -1,23 +1,78 @@
 use super::*;
 
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct Item {
+    /// In pounds
+    pub weight: f32,
+    pub base_value: i32,
+}
+
 #[derive(Component, Debug, Clone, ConvertSaveload)]
 pub struct InBackpack {
     pub owner: Entity,
 }
 
-#[derive(Component, Debug, Clone, ConvertSaveload)]
-pub struct ProvidesHealing(pub i32);
+/// Name of the drop table rolled when a mob dies
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct LootTable(pub String);
+
+/// A mob that leaves its body behind when it dies
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct LeavesCorpse {
+    pub edible: bool,
+    /// In pounds
+    pub weight: f32,
+    pub nutrition: i32,
+    pub poison: Option<i32>,
+}
 
 #[derive(Component, Debug, Clone, ConvertSaveload)]
-pub struct InflictsDamage(pub i32);
+pub struct ProvidesHealing(pub i32);
 
 #[derive(Component, Debug, Clone, ConvertSaveload)]
-pub struct Confusion {
-    pub turns: i32,
+pub struct InflictsDamage {
+    pub amount: i32,
+    pub damage_type: DamageType,
 }
 
 #[derive(Component, Debug, Serialize, Deserialize, Clone)]
 pub struct MagicMapper {}
 
-impl_new!(Confusion, turns: i32);
+/// Reveals the true names of everything the user is carrying
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct ProvidesIdentification {}
+
+/// An item that can be used again and again, until its charges run out
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Charges {
+    pub remaining: i32,
+    pub max: i32,
+    /// Whether the item crumbles away once it's spent, rather than sitting inert until recharged
+    pub crumbles: bool,
+}
+
+/// Restores the charges of everything the user is carrying
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct ProvidesRecharge {}
+
+/// Lifts the curse from everything the user is carrying
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct ProvidesRemoveCurse {}
+
+/// A bundle of arrows, bolts and the like. `Item::weight` is the weight of a single shot.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct Ammunition {
+    pub kind: String,
+    pub count: i32,
+    /// Percent chance that a shot can be picked up again where it lands
+    pub recover_chance: i32,
+}
+
+/// How much an item weighs in total, counting every shot in a bundle of ammunition
+pub fn total_weight(item: &Item, ammo: Option<&Ammunition>) -> f32 {
+    item.weight * ammo.map_or(1, |ammo| ammo.count) as f32
+}
+
+impl_new!(Item, weight: f32, base_value: i32);
 impl_new!(InBackpack, owner: Entity);
+impl_new!(InflictsDamage, amount: i32, damage_type: DamageType);
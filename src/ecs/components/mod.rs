@@ -3,34 +3,48 @@ use crate::prelude::*;
 use specs::prelude::*;
 use specs::Component;
 
+mod ai;
 mod combat;
+mod damage;
 mod description;
 mod equipment;
 mod fov;
 mod glyph;
+mod hazard;
 mod hunger;
+mod initiative;
 mod intent;
 mod items;
+mod movement;
 mod name;
 mod particles;
 mod position;
 mod ranged;
+mod spells;
 mod stats;
+mod status;
 mod tags;
 mod trigger;
 
+pub use ai::*;
 pub use combat::*;
+pub use damage::*;
 pub use description::*;
 pub use equipment::*;
 pub use fov::*;
 pub use glyph::*;
+pub use hazard::*;
 pub use hunger::*;
+pub use initiative::*;
 pub use intent::*;
 pub use items::*;
+pub use movement::*;
 pub use name::*;
 pub use particles::*;
 pub use position::*;
 pub use ranged::*;
+pub use spells::*;
 pub use stats::*;
+pub use status::*;
 pub use tags::*;
 pub use trigger::*;
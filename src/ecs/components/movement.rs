@@ -0,0 +1,25 @@
+use super::*;
+
+/// Sends whoever it hits somewhere random on the map
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Teleports {}
+
+/// Moves the user to the tile it was aimed at
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct TeleportsToTarget {}
+
+/// Sends whoever it hits somewhere random, no more than this many tiles away
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Blinks(pub i32);
+
+/// Shoves whoever it hits this many tiles away from the user
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct KnocksBack(pub i32);
+
+/// Drags whoever it hits up to this many tiles towards the user
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct Pulls(pub i32);
+
+/// Trades places between the user and whoever it hits
+#[derive(Component, Debug, Serialize, Deserialize, Clone)]
+pub struct SwapsPlaces {}
@@ -9,7 +9,18 @@ impl ParticleBuilder {
     pub fn new() -> ParticleBuilder { ParticleBuilder { requests: Vec::new() } }
 
     pub fn request(&mut self, pt: Point, color: ColorPair, glyph: FontCharType, lifetime: f32) {
-        self.requests.push(ParticleRequest::new(lifetime, pt, color, glyph));
+        self.requests.push(ParticleRequest::new(lifetime, pt, color, glyph, None));
+    }
+
+    /// Sends a single particle along `path`, spending `step_time` ms on each tile
+    pub fn request_path(&mut self, path: Vec<Point>, color: ColorPair, glyph: FontCharType, step_time: f32) {
+        if path.len() < 2 {
+            return;
+        }
+
+        let lifetime = step_time * path.len() as f32;
+        let animation = ParticleAnimation::new(0.0, step_time, path.clone(), 0);
+        self.requests.push(ParticleRequest::new(lifetime, path[0], color, glyph, Some(animation)));
     }
 }
 
@@ -25,9 +36,10 @@ pub struct ParticleRequest {
     pub lifetime: f32,
     pub color: ColorPair,
     pub glyph: FontCharType,
+    pub animation: Option<ParticleAnimation>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParticleAnimation {
     pub timer: f32,
     pub step_time: f32,
@@ -55,7 +67,14 @@ pub struct SpawnParticleBurst {
     pub glyph: FontCharType,
 }
 
-impl_new!(ParticleRequest, lifetime: f32, pt: Point, color: ColorPair, glyph: FontCharType);
+impl_new!(
+    ParticleRequest,
+    lifetime: f32,
+    pt: Point,
+    color: ColorPair,
+    glyph: FontCharType,
+    animation: Option<ParticleAnimation>
+);
 impl_new!(ParticleAnimation, timer: f32, step_time: f32, path: Vec<Point>, current_step: usize);
 impl_new!(ParticleLifetime, lifetime_ms: f32, animation: Option<ParticleAnimation>);
 impl_new!(SpawnParticleLine, glyph: FontCharType, color: RGB, lifetime_ms: f32);
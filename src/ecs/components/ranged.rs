@@ -3,9 +3,26 @@ use super::*;
 #[derive(Component, Debug, ConvertSaveload)]
 pub struct Ranged(pub i32);
 
-#[derive(Component, Debug, ConvertSaveload)]
+/// The pattern an area of effect covers
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AoeShape {
+    /// Everything within `radius` of the target that the blast can reach
+    #[default]
+    Circle,
+    /// A wedge spreading out from the user towards the target, `radius` tiles long
+    Cone,
+    /// A beam from the user through the target, `radius` tiles long, piercing everyone in its way
+    Line,
+    /// Arms reaching `radius` tiles out from the target in each cardinal direction
+    Cross,
+    /// Only the rim of the circle, sparing the middle
+    Ring,
+}
+
+#[derive(Component, Debug, Copy, Clone, ConvertSaveload)]
 pub struct AreaOfEffect {
+    pub shape: AoeShape,
     pub radius: i32,
 }
 
-impl_new!(AreaOfEffect, radius: i32);
+impl_new!(AreaOfEffect, shape: AoeShape, radius: i32);
@@ -0,0 +1,18 @@
+use super::*;
+
+/// A spell its owner knows. The effects it has when cast are components on the same entity, just
+/// as they are on a usable item.
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct Spell {
+    pub owner: Entity,
+    pub mana_cost: i32,
+}
+
+/// Teaches the user the named spell from the raws
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct TeachesSpell(pub String);
+
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct ProvidesMana(pub i32);
+
+impl_new!(Spell, owner: Entity, mana_cost: i32);
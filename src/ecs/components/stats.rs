@@ -15,3 +15,164 @@ impl CombatStats {
         CombatStats { max_hp, hp, defense, power }
     }
 }
+
+/// Mana spent casting spells. The base is what the entity was created with, before intelligence
+/// is taken into account.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct ManaPool {
+    pub base_max: i32,
+    pub max: i32,
+    pub current: i32,
+    /// Turns until the next point of mana comes back
+    pub regen_timer: i32,
+}
+
+impl ManaPool {
+    pub fn new(max: i32) -> Self { ManaPool { base_max: max, max, current: max, regen_timer: 0 } }
+}
+
+/// An attribute score of this gives no bonus or penalty
+pub const AVERAGE_ATTRIBUTE: i32 = 10;
+
+/// Bonus (or penalty) granted by an attribute score: +1 for every two points above average
+pub fn attr_bonus(value: i32) -> i32 { (value - AVERAGE_ATTRIBUTE).div_euclid(2) }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attribute {
+    pub base: i32,
+    pub modifiers: i32,
+    pub bonus: i32,
+}
+
+impl Attribute {
+    pub fn new(base: i32) -> Self { Attribute { base, modifiers: 0, bonus: attr_bonus(base) } }
+
+    pub fn value(&self) -> i32 { self.base + self.modifiers }
+}
+
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct Attributes {
+    pub might: Attribute,
+    pub fitness: Attribute,
+    pub quickness: Attribute,
+    pub intelligence: Attribute,
+}
+
+impl Attributes {
+    pub fn new(might: i32, fitness: i32, quickness: i32, intelligence: i32) -> Self {
+        Attributes {
+            might: Attribute::new(might),
+            fitness: Attribute::new(fitness),
+            quickness: Attribute::new(quickness),
+            intelligence: Attribute::new(intelligence),
+        }
+    }
+}
+
+impl Default for Attributes {
+    fn default() -> Self {
+        Self::new(AVERAGE_ATTRIBUTE, AVERAGE_ATTRIBUTE, AVERAGE_ATTRIBUTE, AVERAGE_ATTRIBUTE)
+    }
+}
+
+/// Loads beyond this multiple of carry capacity can't be picked up at all
+pub const MAX_LOAD_MULTIPLIER: f32 = 2.0;
+/// Loads beyond this multiple of carry capacity are overloaded rather than just burdened
+const OVERLOADED_MULTIPLIER: f32 = 1.5;
+
+/// How weighed down an entity is by everything it carries and wears
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Encumbrance {
+    #[default]
+    Unburdened,
+    Burdened,
+    Overloaded,
+}
+
+impl Encumbrance {
+    pub fn from_weight(weight: f32, carry_capacity: i32) -> Self {
+        let capacity = carry_capacity as f32;
+        if weight <= capacity {
+            Encumbrance::Unburdened
+        } else if weight <= capacity * OVERLOADED_MULTIPLIER {
+            Encumbrance::Burdened
+        } else {
+            Encumbrance::Overloaded
+        }
+    }
+
+    pub fn speed_penalty(&self) -> i32 {
+        match self {
+            Encumbrance::Unburdened => 0,
+            Encumbrance::Burdened => 2,
+            Encumbrance::Overloaded => 5,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encumbrance::Unburdened => "Unburdened",
+            Encumbrance::Burdened => "Burdened",
+            Encumbrance::Overloaded => "Overloaded",
+        }
+    }
+}
+
+/// Numbers worked out from `Attributes` by the `AttributeSystem`. The bases are what the entity
+/// was created with, before any attribute bonuses are applied.
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct DerivedStats {
+    pub base_max_hp: i32,
+    pub base_speed: i32,
+    pub to_hit: i32,
+    pub melee_damage: i32,
+    pub carry_capacity: i32,
+    pub carried_weight: f32,
+    pub encumbrance: Encumbrance,
+}
+
+impl DerivedStats {
+    pub fn new(base_max_hp: i32, base_speed: i32) -> Self {
+        DerivedStats {
+            base_max_hp,
+            base_speed,
+            to_hit: 0,
+            melee_damage: 0,
+            carry_capacity: 0,
+            carried_weight: 0.0,
+            encumbrance: Encumbrance::Unburdened,
+        }
+    }
+
+    /// Heaviest total load this entity can pick up to
+    pub fn max_load(&self) -> f32 { self.carry_capacity as f32 * MAX_LOAD_MULTIPLIER }
+}
+
+/// Experience needed per character level; reaching `level * XP_PER_LEVEL` total xp levels you up
+pub const XP_PER_LEVEL: i32 = 1000;
+/// Experience awarded per level of whatever was killed
+pub const XP_PER_KILL_LEVEL: i32 = 100;
+/// Extra max hp granted on each level up
+pub const HP_PER_LEVEL: i32 = 5;
+
+#[derive(Component, Debug, Clone, ConvertSaveload)]
+pub struct Experience {
+    pub level: i32,
+    pub xp: i32,
+    /// Level ups the player hasn't picked an improvement for yet
+    pub pending_choices: i32,
+}
+
+impl Experience {
+    pub fn new(level: i32) -> Self {
+        assert!(level > 0);
+
+        Experience { level, xp: (level - 1) * XP_PER_LEVEL, pending_choices: 0 }
+    }
+
+    /// Total xp at which the next level is reached
+    pub fn next_level_xp(&self) -> i32 { self.level * XP_PER_LEVEL }
+
+    /// Total xp at which the current level was reached
+    pub fn level_start_xp(&self) -> i32 { (self.level - 1) * XP_PER_LEVEL }
+}
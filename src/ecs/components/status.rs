@@ -0,0 +1,164 @@
+use super::*;
+
+/// Turns a status lasts when its raw doesn't say otherwise
+pub const DEFAULT_STATUS_DURATION: i32 = 5;
+/// How far a blinded entity can still see
+pub const BLIND_VISION_RANGE: i32 = 1;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusKind {
+    Confusion,
+    Poison,
+    Regeneration,
+    Haste,
+    Slow,
+    Blindness,
+    Paralysis,
+}
+
+/// How a fresh dose of a status combines with one that is already running
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Stacking {
+    /// Keep the stronger magnitude and the longer duration
+    Refresh,
+    /// Add the new duration onto what's left of the old one
+    Extend,
+    /// Add the magnitudes together and keep the longer duration
+    Intensify,
+}
+
+impl StatusKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatusKind::Confusion => "Confused",
+            StatusKind::Poison => "Poisoned",
+            StatusKind::Regeneration => "Regenerating",
+            StatusKind::Haste => "Hasted",
+            StatusKind::Slow => "Slowed",
+            StatusKind::Blindness => "Blind",
+            StatusKind::Paralysis => "Paralyzed",
+        }
+    }
+
+    pub fn color(&self) -> (u8, u8, u8) {
+        match self {
+            StatusKind::Confusion => MAGENTA,
+            StatusKind::Poison => GREEN,
+            StatusKind::Regeneration => LIGHTGREEN,
+            StatusKind::Haste => CYAN,
+            StatusKind::Slow => ORANGE,
+            StatusKind::Blindness => GRAY,
+            StatusKind::Paralysis => YELLOW,
+        }
+    }
+
+    pub fn stacking(&self) -> Stacking {
+        match self {
+            StatusKind::Poison => Stacking::Intensify,
+            StatusKind::Confusion | StatusKind::Blindness => Stacking::Extend,
+            StatusKind::Regeneration | StatusKind::Haste | StatusKind::Slow | StatusKind::Paralysis => {
+                Stacking::Refresh
+            }
+        }
+    }
+
+    /// On-apply hook: what the log says when the status takes hold
+    pub fn apply_message(&self) -> &'static str {
+        match self {
+            StatusKind::Confusion => "is confused!",
+            StatusKind::Poison => "is poisoned!",
+            StatusKind::Regeneration => "begins to regenerate.",
+            StatusKind::Haste => "speeds up!",
+            StatusKind::Slow => "slows down.",
+            StatusKind::Blindness => "is blinded!",
+            StatusKind::Paralysis => "is paralyzed!",
+        }
+    }
+
+    /// On-tick hook: the effect fired at the afflicted entity at the start of each of its turns
+    pub fn tick_effect(&self, magnitude: i32) -> Option<EffectType> {
+        match self {
+            StatusKind::Poison => Some(EffectType::Damage(magnitude, DamageType::Poison)),
+            StatusKind::Regeneration => Some(EffectType::Healing(magnitude)),
+            _ => None,
+        }
+    }
+
+    /// On-expire hook: what the log says when the status wears off
+    pub fn expire_message(&self) -> &'static str {
+        match self {
+            StatusKind::Confusion => "is no longer confused.",
+            StatusKind::Poison => "is no longer poisoned.",
+            StatusKind::Regeneration => "stops regenerating.",
+            StatusKind::Haste => "slows back down.",
+            StatusKind::Slow => "speeds back up.",
+            StatusKind::Blindness => "can see again.",
+            StatusKind::Paralysis => "can move again.",
+        }
+    }
+
+    /// Statuses that change speed need derived stats recalculated when they start or stop
+    pub fn changes_stats(&self) -> bool { matches!(self, StatusKind::Haste | StatusKind::Slow) }
+
+    /// Statuses that change sight need the field of view recalculated when they start or stop
+    pub fn changes_sight(&self) -> bool { matches!(self, StatusKind::Blindness) }
+
+    /// Statuses that stop the afflicted entity from taking actions of its own
+    pub fn prevents_action(&self) -> bool { matches!(self, StatusKind::Paralysis) }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveStatus {
+    pub kind: StatusKind,
+    pub turns: i32,
+    pub magnitude: i32,
+}
+
+/// Every timed status currently afflicting an entity
+#[derive(Component, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusEffects {
+    pub effects: Vec<ActiveStatus>,
+}
+
+impl StatusEffects {
+    pub fn has(&self, kind: StatusKind) -> bool { self.effects.iter().any(|status| status.kind == kind) }
+
+    /// Combined magnitude of `kind`, or 0 if it isn't active
+    pub fn magnitude(&self, kind: StatusKind) -> i32 {
+        self.effects.iter().filter(|status| status.kind == kind).map(|status| status.magnitude).sum()
+    }
+
+    pub fn prevents_action(&self) -> bool { self.effects.iter().any(|status| status.kind.prevents_action()) }
+
+    /// Adds a status according to its stacking rule. Returns `true` if the status is new.
+    pub fn apply(&mut self, kind: StatusKind, turns: i32, magnitude: i32) -> bool {
+        match self.effects.iter_mut().find(|status| status.kind == kind) {
+            Some(existing) => {
+                match kind.stacking() {
+                    Stacking::Refresh => {
+                        existing.turns = i32::max(existing.turns, turns);
+                        existing.magnitude = i32::max(existing.magnitude, magnitude);
+                    }
+                    Stacking::Extend => existing.turns += turns,
+                    Stacking::Intensify => {
+                        existing.turns = i32::max(existing.turns, turns);
+                        existing.magnitude += magnitude;
+                    }
+                }
+
+                false
+            }
+            None => {
+                self.effects.push(ActiveStatus { kind, turns, magnitude });
+                true
+            }
+        }
+    }
+}
+
+/// Statuses an item or trap puts on whatever it affects
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct InflictsStatus {
+    pub statuses: Vec<(StatusKind, i32)>,
+    pub turns: i32,
+}
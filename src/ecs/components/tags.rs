@@ -12,10 +12,6 @@ pub struct Monster {}
 #[storage(NullStorage)]
 pub struct BlocksTile {}
 
-#[derive(Component, Default, Clone, Serialize, Deserialize)]
-#[storage(NullStorage)]
-pub struct Item {}
-
 #[derive(Component, Default, Clone, Serialize, Deserialize)]
 #[storage(NullStorage)]
 pub struct Consumable {}
@@ -35,4 +31,13 @@ pub struct Bystander {}
 #[storage(NullStorage)]
 pub struct Vendor {}
 
+#[derive(Component, Default, Clone, Serialize, Deserialize)]
+#[storage(NullStorage)]
+pub struct MyTurn {}
+
+/// Marks an entity whose attributes or gear changed, so its derived stats need recalculating
+#[derive(Component, Default, Clone, Serialize, Deserialize)]
+#[storage(NullStorage)]
+pub struct StatsChanged {}
+
 pub struct SerializeMe {}
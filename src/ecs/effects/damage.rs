@@ -1,58 +1,301 @@
 use super::*;
+use std::collections::HashSet;
 
 pub fn inflict_damage(world: &mut World, damage: &EffectSpawner, target: Entity) {
-    if let Some(stats) = world.write_storage::<CombatStats>().get_mut(target) {
-        if let EffectType::Damage(amount) = damage.effect_type {
-            stats.hp -= amount;
+    if let EffectType::Damage(amount, damage_type) = damage.effect_type {
+        let response = damage_response(world, target, damage_type);
+        let amount = response.apply(amount);
+        log_damage_response(world, target, damage_type, response, amount);
 
-            add_damage_particle(target);
-            if let Some(blood) = world.read_storage::<Blood>().get(target) {
-                add_effect(None, EffectType::Bloodstain(blood.0), Targets::Single(target));
-            }
+        if amount < 1 {
+            return;
+        }
 
-            // Events
-            let player_entity = world.fetch::<Entity>();
-            if target == *player_entity {
-                bo_logging::record_event("Damage Taken", amount);
+        let killed = match world.write_storage::<CombatStats>().get_mut(target) {
+            Some(stats) => {
+                stats.hp -= amount;
+                stats.hp < 1
             }
+            None => return,
+        };
 
-            if let Some(creator) = damage.creator {
-                if creator == *player_entity {
-                    bo_logging::record_event("Damage Inflicted", amount);
-                }
-            }
+        world.write_resource::<EffectQueue>().add_damage_particle(target);
+        if let Some(blood) = world.read_storage::<Blood>().get(target) {
+            add_effect(world, None, EffectType::Bloodstain(blood.0), Targets::Single(target));
+        }
 
-            if stats.hp < 1 {
-                add_effect(damage.creator, EffectType::EntityDeath, Targets::Single(target));
-            }
+        notify(world, EffectEvent::DamageTaken { source: damage.creator, target, amount, damage_type });
+
+        if killed {
+            add_effect(world, damage.creator, EffectType::EntityDeath, Targets::Single(target));
+        }
+    }
+}
+
+/// Keeps the running totals shown when the game ends
+pub fn record_combat_events(world: &mut World, event: &EffectEvent) {
+    if let EffectEvent::DamageTaken { source, target, amount, .. } = *event {
+        let player_entity = *world.fetch::<Entity>();
+        if target == player_entity {
+            bo_logging::record_event("Damage Taken", amount);
         }
+
+        if source == Some(player_entity) {
+            bo_logging::record_event("Damage Inflicted", amount);
+        }
+    }
+}
+
+/// Weighs the target's own resistances together with those of any armour it is wearing
+fn damage_response(world: &World, target: Entity, damage_type: DamageType) -> DamageResponse {
+    let resistances = world.read_storage::<Resistances>();
+    let equipped = world.read_storage::<Equipped>();
+
+    let worn =
+        (&resistances, &equipped).join().filter(|(_, equip)| equip.owner == target).map(|(res, _)| res);
+    Resistances::response(resistances.get(target).into_iter().chain(worn), damage_type)
+}
+
+fn log_damage_response(
+    world: &World,
+    target: Entity,
+    damage_type: DamageType,
+    response: DamageResponse,
+    amount: i32,
+) {
+    let text = match response {
+        DamageResponse::Normal => return,
+        DamageResponse::Resistant => "resists, taking only",
+        DamageResponse::Immune => "is immune, taking",
+        DamageResponse::Vulnerable => "is vulnerable, taking",
+    };
+
+    if let Some(name) = world.read_storage::<Name>().get(target) {
+        bo_logging::Logger::new()
+            .npc_name(&name.0)
+            .append(text)
+            .damage(amount)
+            .color(damage_type.color())
+            .append(damage_type.name())
+            .color(WHITE)
+            .append("damage.")
+            .log();
     }
 }
 
-pub fn bloodstain(world: &mut World, tile_idx: usize, blood: RGB) {
-    let mut map = world.fetch_mut::<Map>();
-    map.bloodstains.insert(tile_idx, blood);
+pub fn bloodstain(world: &mut World, effect: &EffectSpawner, tile_idx: usize) {
+    if let EffectType::Bloodstain(blood) = effect.effect_type {
+        let mut map = world.fetch_mut::<Map>();
+        map.bloodstains.insert(tile_idx, blood);
+    }
 }
 
-pub fn death(ecs: &mut World, _effect: &EffectSpawner, target: Entity) {
+pub fn death(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
     if let Some(pos) = entity_position(ecs, target) {
         crate::spatial::remove_entity(target, pos as usize);
     }
+
+    notify(ecs, EffectEvent::Killed { killer: effect.creator, victim: target });
+
+    drop_remains(ecs, target);
 }
 
-pub fn heal_damage(ecs: &mut World, heal: &EffectSpawner, target: Entity) {
-    if let Some(stats) = ecs.write_storage::<CombatStats>().get_mut(target) {
-        if let EffectType::Healing(amount) = heal.effect_type {
-            stats.hp = i32::min(stats.max_hp, stats.hp + amount);
-            add_heal_particle(target);
+pub fn award_experience(ecs: &mut World, event: &EffectEvent) {
+    let (killer, target) = match *event {
+        EffectEvent::Killed { killer: Some(killer), victim } => (killer, victim),
+        _ => return,
+    };
+
+    let mut experience = ecs.write_storage::<Experience>();
+
+    // Taking the victim's experience means a second death effect can't pay out again
+    let xp = match experience.remove(target) {
+        Some(victim) => victim.level * XP_PER_KILL_LEVEL,
+        None => return,
+    };
+
+    let killer_xp = match experience.get_mut(killer) {
+        Some(killer_xp) => killer_xp,
+        None => return,
+    };
+
+    let is_player = killer == *ecs.fetch::<Entity>();
+    killer_xp.xp += xp;
+
+    while killer_xp.xp >= killer_xp.next_level_xp() {
+        killer_xp.level += 1;
+
+        if let Some(derived) = ecs.write_storage::<DerivedStats>().get_mut(killer) {
+            derived.base_max_hp += HP_PER_LEVEL;
+        }
+        ecs.write_storage::<StatsChanged>()
+            .insert(killer, StatsChanged {})
+            .expect("Unable to insert marker");
+
+        if is_player {
+            killer_xp.pending_choices += 1;
+
+            // Back to full health; the new hit points are added once stats are recalculated
+            if let Some(stats) = ecs.write_storage::<CombatStats>().get_mut(killer) {
+                stats.hp = stats.max_hp;
+            }
+
+            bo_logging::Logger::new()
+                .color(MAGENTA)
+                .append("Congratulations, you are now level")
+                .append(killer_xp.level)
+                .log();
+        } else if let Some(name) = ecs.read_storage::<Name>().get(killer) {
+            bo_logging::Logger::new().npc_name(&name.0).append("grows stronger!").log();
         }
     }
 }
 
-pub fn add_confusion(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
-    if let EffectType::Confusion(turns) = &effect.effect_type {
-        ecs.write_storage::<Confusion>()
-            .insert(target, Confusion { turns: *turns })
-            .expect("Unable to insert status");
+/// Leaves a dying mob's corpse, everything it was carrying or wearing, and whatever its drop table
+/// rolls on the tile it fell on, spilling onto the nearest free tiles once that one is taken
+fn drop_remains(ecs: &mut World, target: Entity) {
+    if target == *ecs.fetch::<Entity>() {
+        return;
+    }
+
+    let target_pos = match ecs.read_storage::<Point>().get(target) {
+        Some(pos) => *pos,
+        None => return,
+    };
+
+    // The corpse goes down first, so it lands right where the mob fell
+    let mut drops: Vec<Entity> = Vec::new();
+    let corpse = ecs.write_storage::<LeavesCorpse>().remove(target);
+    if let Some(corpse) = corpse {
+        drops.push(spawn_corpse(ecs, target, &corpse));
+    }
+
+    {
+        let entities = ecs.entities();
+        let mut backpack = ecs.write_storage::<InBackpack>();
+        let mut equipped = ecs.write_storage::<Equipped>();
+
+        let carried: Vec<Entity> = (&entities)
+            .join()
+            .filter(|item| {
+                backpack.get(*item).is_some_and(|pack| pack.owner == target)
+                    || equipped.get(*item).is_some_and(|equip| equip.owner == target)
+            })
+            .collect();
+
+        for item in carried {
+            backpack.remove(item);
+            equipped.remove(item);
+            drops.push(item);
+        }
+    }
+
+    // Taking the drop table means a second death effect can't roll it again
+    let loot_table = ecs.write_storage::<LootTable>().remove(target);
+    if let Some(loot_table) = loot_table {
+        let loot = raws::get_loot_drops(&RAWS.lock(), &loot_table.0);
+        for (name, quantity) in loot {
+            drops.extend(spawn_loot(ecs, &name, quantity, target_pos));
+        }
+    }
+
+    if drops.is_empty() {
+        return;
+    }
+
+    let tiles = {
+        let map = ecs.fetch::<Map>();
+        let entities = ecs.entities();
+        let positions = ecs.read_storage::<Point>();
+        let items = ecs.read_storage::<Item>();
+        let blockers = ecs.read_storage::<BlocksTile>();
+
+        let occupied: HashSet<usize> = (&entities, &positions)
+            .join()
+            .filter(|(entity, _)| {
+                *entity != target
+                    && !drops.contains(entity)
+                    && (items.contains(*entity) || blockers.contains(*entity))
+            })
+            .map(|(_, pt)| map.point2d_to_index(*pt))
+            .collect();
+
+        raws::find_free_tiles_near(&map, target_pos, drops.len(), |idx| occupied.contains(&idx))
+    };
+
+    let mut positions = ecs.write_storage::<Point>();
+    for (i, item) in drops.into_iter().enumerate() {
+        let pt = tiles.get(i).copied().unwrap_or(target_pos);
+        positions.insert(item, pt).expect("Unable to insert position");
+    }
+}
+
+fn spawn_corpse(ecs: &mut World, target: Entity, corpse: &LeavesCorpse) -> Entity {
+    let name = ecs.read_storage::<Name>().get(target).map_or("Unknown".to_string(), |name| name.0.clone());
+    let color =
+        ecs.read_storage::<Glyph>().get(target).map_or(ColorPair::new(WHITE, BLACK), |glyph| glyph.color);
+
+    let mut eb = ecs
+        .create_entity()
+        .with(Name(format!("{} Corpse", name)))
+        .with(Glyph::new(to_cp437('%'), color, RenderOrder::Item))
+        .with(Item::new(corpse.weight, 0))
+        .marked::<SimpleMarker<SerializeMe>>();
+
+    if corpse.edible {
+        eb = eb.with(ProvidesFood(corpse.nutrition)).with(Consumable {});
+
+        if let Some(poison) = corpse.poison {
+            eb = eb.with(InflictsStatus {
+                statuses: vec![(StatusKind::Poison, poison)],
+                turns: DEFAULT_STATUS_DURATION,
+            });
+        }
+    }
+
+    eb.build()
+}
+
+/// Spawns `quantity` of a dropped item. Ammunition drops as a single bundle of that many shots.
+fn spawn_loot(ecs: &mut World, name: &str, quantity: i32, pos: Point) -> Vec<Entity> {
+    let mut spawned = Vec::new();
+
+    for _ in 0..quantity {
+        let item = match raws::spawn_named_entity(ecs, name, SpawnType::AtPosition(pos)) {
+            Some(item) => item,
+            None => break,
+        };
+
+        spawned.push(item);
+        if let Some(ammo) = ecs.write_storage::<Ammunition>().get_mut(item) {
+            ammo.count = quantity;
+            break;
+        }
+    }
+
+    spawned
+}
+
+pub fn heal_damage(ecs: &mut World, heal: &EffectSpawner, target: Entity) {
+    if let EffectType::Healing(amount) = heal.effect_type {
+        let healed = match ecs.write_storage::<CombatStats>().get_mut(target) {
+            Some(stats) => {
+                let before = stats.hp;
+                stats.hp = i32::min(stats.max_hp, stats.hp + amount);
+                stats.hp - before
+            }
+            None => return,
+        };
+
+        ecs.write_resource::<EffectQueue>().add_heal_particle(target);
+        notify(ecs, EffectEvent::Healed { source: heal.creator, target, amount: healed });
+    }
+}
+
+pub fn restore_mana(ecs: &mut World, mana: &EffectSpawner, target: Entity) {
+    if let Some(pool) = ecs.write_storage::<ManaPool>().get_mut(target) {
+        if let EffectType::RestoreMana(amount) = mana.effect_type {
+            pool.current = i32::min(pool.max, pool.current + amount);
+        }
     }
 }
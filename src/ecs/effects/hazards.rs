@@ -0,0 +1,16 @@
+use super::*;
+
+pub fn create_hazard(ecs: &mut World, effect: &EffectSpawner, tile_idx: usize) {
+    if let EffectType::Hazard(kind, turns) = effect.effect_type {
+        ecs.fetch_mut::<Map>().add_hazard(tile_idx, kind, turns);
+    }
+}
+
+pub fn douse(ecs: &mut World, _effect: &EffectSpawner, tile_idx: usize) {
+    let mut map = ecs.fetch_mut::<Map>();
+    let seen = map.visible.get_bit(map.index_to_point2d(tile_idx));
+
+    if map.douse(tile_idx) && seen {
+        bo_logging::Logger::new().append("The flames hiss and go out.").log();
+    }
+}
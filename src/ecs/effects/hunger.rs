@@ -1,8 +1,12 @@
 use super::*;
 
-pub fn well_fed(ecs: &mut World, _damage: &EffectSpawner, target: Entity) {
+pub fn feed(ecs: &mut World, food: &EffectSpawner, target: Entity) {
+    let config = *ecs.fetch::<HungerConfig>();
+
     if let Some(hc) = ecs.write_storage::<HungerClock>().get_mut(target) {
-        hc.state = HungerState::WellFed;
-        hc.duration = 20;
+        if let EffectType::Feed(nutrition) = food.effect_type {
+            hc.nutrition = i32::min(config.max_nutrition, hc.nutrition + nutrition);
+            hc.state = config.state_for(hc.nutrition);
+        }
     }
 }
@@ -1,42 +1,69 @@
 use crate::prelude::*;
-use lazy_static::lazy_static;
-use parking_lot::Mutex;
 use std::collections::VecDeque;
 
 mod damage;
+mod hazards;
 mod hunger;
+mod movement;
 mod particles;
+mod registry;
+mod status;
 mod targeting;
 mod triggers;
 
 pub use hunger::*;
 pub use particles::*;
+pub use registry::*;
 pub use targeting::*;
 
-lazy_static! {
-    pub static ref EFFECT_QUEUE: Mutex<VecDeque<EffectSpawner>> = Mutex::new(VecDeque::new());
-}
-
+/// Every effect waiting to be applied. Systems queue effects here, and `run_effects_queue` drains it
+/// once they have all run.
 #[derive(Debug, Default)]
-pub struct EffectQueue(pub VecDeque<EffectSpawner>);
+pub struct EffectQueue(VecDeque<EffectSpawner>);
 
 impl EffectQueue {
     pub fn new() -> Self { Self(VecDeque::new()) }
 
-    pub fn add_effect(&mut self, effect: EffectSpawner) { self.0.push_back(effect) }
+    pub fn add_effect(&mut self, creator: Option<Entity>, effect_type: EffectType, targets: Targets) {
+        self.0.push_back(EffectSpawner { creator, effect_type, targets })
+    }
+
+    pub fn add_single_damage_effect(
+        &mut self,
+        creator: Option<Entity>,
+        target: Entity,
+        amount: i32,
+        damage_type: DamageType,
+    ) {
+        self.add_effect(creator, EffectType::Damage(amount, damage_type), Targets::Single(target));
+    }
+
+    pub fn add_single_healing_effect(&mut self, creator: Option<Entity>, target: Entity, amount: i32) {
+        self.add_effect(creator, EffectType::Healing(amount), Targets::Single(target));
+    }
 }
 
 #[derive(Debug)]
 pub enum EffectType {
-    WellFed,
+    Feed(i32),
     EntityDeath,
     Bloodstain(RGB),
-    Damage(i32),
+    Damage(i32, DamageType),
     Healing(i32),
-    Confusion(i32),
+    RestoreMana(i32),
+    Status(StatusKind, i32, i32),
     ItemUse(Entity),
+    SpellUse(Entity),
     TriggerFire(Entity),
     Particle(FontCharType, ColorPair, f32),
+    /// To the given tile, or anywhere on the map
+    Teleport(Option<Point>),
+    Blink(i32),
+    Knockback(i32),
+    Pull(i32),
+    SwapPlaces,
+    Hazard(HazardKind, i32),
+    Douse,
 }
 
 #[derive(Clone, Debug)]
@@ -58,23 +85,16 @@ pub struct EffectSpawner {
 // Add Effects
 ///////////////////////////////////////////////////////////////////////////////////////////////
 
-pub fn add_effect(creator: Option<Entity>, effect_type: EffectType, targets: Targets) {
-    EFFECT_QUEUE.lock().push_back(EffectSpawner { creator, effect_type, targets });
-}
-
-pub fn add_single_damage_effect(creator: Option<Entity>, target: Entity, amount: i32) {
-    add_effect(creator, EffectType::Damage(amount), Targets::Single(target));
-}
-
-pub fn add_single_healing_effect(creator: Option<Entity>, target: Entity, amount: i32) {
-    add_effect(creator, EffectType::Healing(amount), Targets::Single(target));
+/// Queues an effect from code that holds the world rather than the queue itself
+pub fn add_effect(ecs: &World, creator: Option<Entity>, effect_type: EffectType, targets: Targets) {
+    ecs.write_resource::<EffectQueue>().add_effect(creator, effect_type, targets);
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////
 
 pub fn run_effects_queue(ecs: &mut World) {
     loop {
-        let effect: Option<EffectSpawner> = EFFECT_QUEUE.lock().pop_front();
+        let effect = ecs.write_resource::<EffectQueue>().0.pop_front();
         if let Some(effect) = effect {
             target_applicator(ecs, &effect);
         } else {
@@ -84,61 +104,54 @@ pub fn run_effects_queue(ecs: &mut World) {
 }
 
 fn target_applicator(ecs: &mut World, effect: &EffectSpawner) {
-    if let EffectType::ItemUse(item) = effect.effect_type {
-        triggers::item_trigger(effect.creator, item, &effect.targets, ecs);
-    } else if let EffectType::TriggerFire(trigger) = effect.effect_type {
-        triggers::trigger(effect.creator, trigger, &effect.targets, ecs);
-    } else {
-        match &effect.targets {
-            Targets::Tile(tile_idx) => affect_tile(ecs, effect, *tile_idx),
-            Targets::Tiles(tiles) => tiles.iter().for_each(|tile_idx| affect_tile(ecs, effect, *tile_idx)),
-            Targets::Single(target) => affect_entity(ecs, effect, *target),
-            Targets::TargetList(targets) => {
-                targets.iter().for_each(|entity| affect_entity(ecs, effect, *entity))
-            }
-        }
+    // Some effects deal with their targets all at once
+    let handler = ecs.fetch::<EffectRegistry>().effect_handler(effect.effect_type.kind());
+    if let Some(handler) = handler {
+        handler(ecs, effect);
+        return;
     }
-}
 
-fn tile_effect_hits_entities(effect: &EffectType) -> bool {
-    matches!(
-        effect,
-        EffectType::Damage { .. }
-            | EffectType::Healing { .. }
-            | EffectType::Confusion { .. }
-            | EffectType::WellFed
-    )
+    match &effect.targets {
+        Targets::Tile(tile_idx) => affect_tile(ecs, effect, *tile_idx),
+        Targets::Tiles(tiles) => tiles.iter().for_each(|tile_idx| affect_tile(ecs, effect, *tile_idx)),
+        Targets::Single(target) => affect_entity(ecs, effect, *target),
+        Targets::TargetList(targets) => targets.iter().for_each(|entity| affect_entity(ecs, effect, *entity)),
+    }
 }
 
+/// An effect aimed at a tile hits everything standing there, if it can affect entities at all
 fn affect_tile(ecs: &mut World, effect: &EffectSpawner, tile_idx: usize) {
-    if tile_effect_hits_entities(&effect.effect_type) {
-        crate::spatial::for_each_tile_content(tile_idx as usize, |entity| affect_entity(ecs, effect, entity));
+    let (entity_handler, tile_handler) = {
+        let registry = ecs.fetch::<EffectRegistry>();
+        let kind = effect.effect_type.kind();
+        (registry.entity_handler(kind), registry.tile_handler(kind))
+    };
+
+    // Handlers are free to touch the spatial map, so they can't run while it is being walked
+    if let Some(handler) = entity_handler {
+        for entity in crate::spatial::get_tile_content_clone(tile_idx) {
+            handler(ecs, effect, entity);
+        }
     }
 
-    match &effect.effect_type {
-        EffectType::Bloodstain(blood) => damage::bloodstain(ecs, tile_idx, *blood),
-        EffectType::Particle { .. } => particles::particle_to_tile(ecs, tile_idx, effect),
-        _ => {}
+    if let Some(handler) = tile_handler {
+        handler(ecs, effect, tile_idx);
     }
 }
 
+/// An effect aimed at an entity that only knows how to affect tiles lands where the entity stands
 fn affect_entity(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
-    match &effect.effect_type {
-        EffectType::WellFed => hunger::well_fed(ecs, effect, target),
-        EffectType::EntityDeath => damage::death(ecs, effect, target),
-        EffectType::Healing { .. } => damage::heal_damage(ecs, effect, target),
-        EffectType::Damage { .. } => damage::inflict_damage(ecs, effect, target),
-        EffectType::Confusion { .. } => damage::add_confusion(ecs, effect, target),
-        EffectType::Particle { .. } => {
-            if let Some(pos) = entity_position(ecs, target) {
-                particles::particle_to_tile(ecs, pos, effect)
-            }
-        }
-        EffectType::Bloodstain(blood) => {
-            if let Some(pos) = entity_position(ecs, target) {
-                damage::bloodstain(ecs, pos, *blood)
-            }
+    let (entity_handler, tile_handler) = {
+        let registry = ecs.fetch::<EffectRegistry>();
+        let kind = effect.effect_type.kind();
+        (registry.entity_handler(kind), registry.tile_handler(kind))
+    };
+
+    if let Some(handler) = entity_handler {
+        handler(ecs, effect, target);
+    } else if let Some(handler) = tile_handler {
+        if let Some(pos) = entity_position(ecs, target) {
+            handler(ecs, effect, pos);
         }
-        _ => {}
     }
 }
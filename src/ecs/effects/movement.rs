@@ -0,0 +1,293 @@
+use super::*;
+
+/// Damage taken for every tile of a knockback that a wall or a body cuts short
+const COLLISION_DAMAGE_PER_TILE: i32 = 2;
+
+pub fn teleport(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
+    let origin = match ecs.read_storage::<Point>().get(target) {
+        Some(pos) => *pos,
+        None => return,
+    };
+
+    let destination = match effect.effect_type {
+        EffectType::Teleport(Some(destination)) => {
+            Some(destination).filter(|pt| ecs.fetch::<Map>().can_enter_tile(*pt))
+        }
+        EffectType::Teleport(None) => random_free_tile(ecs, origin, None),
+        _ => return,
+    };
+
+    match destination {
+        Some(destination) => {
+            move_to(ecs, target, destination);
+            log_movement(ecs, target, "vanishes and reappears elsewhere!");
+        }
+        None => log_movement(ecs, target, "flickers, but stays put."),
+    }
+}
+
+pub fn blink(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
+    let range = match effect.effect_type {
+        EffectType::Blink(range) => range,
+        _ => return,
+    };
+
+    let origin = match ecs.read_storage::<Point>().get(target) {
+        Some(pos) => *pos,
+        None => return,
+    };
+
+    if let Some(destination) = random_free_tile(ecs, origin, Some(range)) {
+        move_to(ecs, target, destination);
+        log_movement(ecs, target, "blinks!");
+    }
+}
+
+/// Shoves the target straight away from whoever made the effect. Anything in the way cuts the
+/// shove short, and both it and the target take damage for every tile left unspent.
+pub fn knockback(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
+    let distance = match effect.effect_type {
+        EffectType::Knockback(distance) => distance,
+        _ => return,
+    };
+
+    let start = match ecs.read_storage::<Point>().get(target) {
+        Some(pos) => *pos,
+        None => return,
+    };
+
+    // Traps go off with their victim as the source, so they shove in any direction
+    let step = match source_position(ecs, effect) {
+        Some(source) if source != start => step_towards(source, start),
+        _ => random_step(),
+    };
+
+    let (destination, travelled) = slide(ecs, start, step, distance, None);
+    if destination != start {
+        move_to(ecs, target, destination);
+        log_movement(ecs, target, "is knocked back!");
+    }
+
+    let remaining = distance - travelled;
+    if remaining < 1 {
+        return;
+    }
+
+    let damage = remaining * COLLISION_DAMAGE_PER_TILE;
+    log_movement(ecs, target, "slams into something!");
+    add_effect(
+        ecs,
+        effect.creator,
+        EffectType::Damage(damage, DamageType::Physical),
+        Targets::Single(target),
+    );
+
+    let obstacle = {
+        let map = ecs.fetch::<Map>();
+        let next = destination + step;
+        if map.in_bounds(next) {
+            crate::spatial::get_blocking_entity(map.point2d_to_index(next))
+        } else {
+            None
+        }
+    };
+
+    if let Some(obstacle) = obstacle.filter(|obstacle| ecs.read_storage::<CombatStats>().contains(*obstacle))
+    {
+        add_effect(
+            ecs,
+            effect.creator,
+            EffectType::Damage(damage, DamageType::Physical),
+            Targets::Single(obstacle),
+        );
+    }
+}
+
+/// Drags the target towards whoever made the effect, stopping at the first thing in the way
+pub fn pull(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
+    let distance = match effect.effect_type {
+        EffectType::Pull(distance) => distance,
+        _ => return,
+    };
+
+    let start = match ecs.read_storage::<Point>().get(target) {
+        Some(pos) => *pos,
+        None => return,
+    };
+
+    let source = match source_position(ecs, effect) {
+        Some(source) if source != start => source,
+        _ => return,
+    };
+
+    let (destination, _) = slide(ecs, start, step_towards(start, source), distance, Some(source));
+    if destination != start {
+        move_to(ecs, target, destination);
+        log_movement(ecs, target, "is pulled in!");
+    }
+}
+
+/// Trades places between whoever made the effect and the target
+pub fn swap_places(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
+    let creator = match effect.creator {
+        Some(creator) if creator != target => creator,
+        _ => return,
+    };
+
+    let (creator_pos, target_pos) = {
+        let positions = ecs.read_storage::<Point>();
+        match (positions.get(creator), positions.get(target)) {
+            (Some(creator_pos), Some(target_pos)) => (*creator_pos, *target_pos),
+            _ => return,
+        }
+    };
+
+    move_to(ecs, creator, target_pos);
+    move_to(ecs, target, creator_pos);
+    log_movement(ecs, target, "swaps places!");
+}
+
+/// Puts `target` down at `destination`, keeping the spatial index, its view, anything waiting to
+/// be triggered underfoot and the camera in step
+fn move_to(ecs: &mut World, target: Entity, destination: Point) {
+    let origin = match ecs.read_storage::<Point>().get(target) {
+        Some(pos) => *pos,
+        None => return,
+    };
+
+    if origin == destination {
+        return;
+    }
+
+    {
+        let map = ecs.fetch::<Map>();
+        crate::spatial::move_entity(target, map.point2d_to_index(origin), map.point2d_to_index(destination));
+    }
+
+    if let Some(pos) = ecs.write_storage::<Point>().get_mut(target) {
+        *pos = destination;
+    }
+
+    if let Some(fov) = ecs.write_storage::<FieldOfView>().get_mut(target) {
+        fov.is_dirty = true;
+    }
+
+    ecs.write_storage::<EntityMoved>().insert(target, EntityMoved {}).expect("Unable to insert marker");
+
+    if target == *ecs.fetch::<Entity>() {
+        ecs.write_resource::<CameraView>().on_player_move(destination);
+        *ecs.write_resource::<Point>() = destination;
+    }
+}
+
+/// Where the effect came from, if whoever made it is still standing somewhere
+fn source_position(ecs: &World, effect: &EffectSpawner) -> Option<Point> {
+    effect.creator.and_then(|creator| ecs.read_storage::<Point>().get(creator).copied())
+}
+
+/// Walks from `start` along `step` for up to `distance` tiles, stopping short of anything blocked or
+/// of `stop_at`. Returns where it ended up and how many tiles it covered.
+fn slide(ecs: &World, start: Point, step: Point, distance: i32, stop_at: Option<Point>) -> (Point, i32) {
+    let map = ecs.fetch::<Map>();
+
+    let mut current = start;
+    let mut travelled = 0;
+    while travelled < distance {
+        let next = current + step;
+        if Some(next) == stop_at || !map.can_enter_tile(next) {
+            break;
+        }
+
+        current = next;
+        travelled += 1;
+    }
+
+    (current, travelled)
+}
+
+/// One tile's step from `from` in the direction of `to`
+fn step_towards(from: Point, to: Point) -> Point {
+    Point::new((to.x - from.x).signum(), (to.y - from.y).signum())
+}
+
+fn random_step() -> Point {
+    match crate::rng::range(0, 8) {
+        0 => Point::new(-1, -1),
+        1 => Point::new(0, -1),
+        2 => Point::new(1, -1),
+        3 => Point::new(-1, 0),
+        4 => Point::new(1, 0),
+        5 => Point::new(-1, 1),
+        6 => Point::new(0, 1),
+        _ => Point::new(1, 1),
+    }
+}
+
+/// A random tile that could be stood on, within `range` of `origin` if there's a limit
+fn random_free_tile(ecs: &World, origin: Point, range: Option<i32>) -> Option<Point> {
+    let map = ecs.fetch::<Map>();
+
+    let candidates: Vec<Point> = (0..map.tiles.len())
+        .map(|idx| map.index_to_point2d(idx))
+        .filter(|pt| *pt != origin && map.can_enter_tile(*pt))
+        .filter(|pt| {
+            range.is_none_or(|range| DistanceAlg::Pythagoras.distance2d(origin, *pt) <= range as f32)
+        })
+        .collect();
+
+    if candidates.is_empty() {
+        None
+    } else {
+        Some(candidates[crate::rng::range(0, candidates.len() as i32) as usize])
+    }
+}
+
+fn log_movement(ecs: &World, target: Entity, text: &str) {
+    if let Some(name) = ecs.read_storage::<Name>().get(target) {
+        bo_logging::Logger::new().npc_name(&name.0).append(text).log();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knockback_aimed_at_a_tile_moves_whoever_is_there() {
+        let _lock = crate::spatial::TEST_LOCK.lock();
+
+        let mut world = World::new();
+        world.register::<Point>();
+        world.register::<FieldOfView>();
+        world.register::<EntityMoved>();
+        world.register::<Name>();
+        world.register::<CombatStats>();
+
+        let map = Map::test_room(10, 10);
+        let (source_pos, target_pos) = (Point::new(2, 5), Point::new(4, 5));
+        let (source_idx, target_idx) = (map.point2d_to_index(source_pos), map.point2d_to_index(target_pos));
+        world.insert(map);
+        world.insert(EffectQueue::new());
+        world.insert(EffectRegistry::new());
+
+        let source = world.create_entity().with(source_pos).build();
+        let target = world.create_entity().with(target_pos).build();
+        crate::spatial::index_entity(source, source_idx, true, false);
+        crate::spatial::index_entity(target, target_idx, true, false);
+
+        world.insert(source);
+        world.insert(source_pos);
+        world.insert(CameraView::new(source_pos));
+
+        add_effect(&world, Some(source), EffectType::Knockback(3), Targets::Tile(target_idx));
+        run_effects_queue(&mut world);
+
+        let landed = Point::new(7, 5);
+        assert_eq!(world.read_storage::<Point>().get(target), Some(&landed));
+        assert!(world.read_storage::<EntityMoved>().contains(target));
+        assert!(crate::spatial::get_tile_content_clone(target_idx).is_empty());
+
+        let landed_idx = world.fetch::<Map>().point2d_to_index(landed);
+        assert_eq!(crate::spatial::get_tile_content_clone(landed_idx), vec![target]);
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-pub fn particle_to_tile(ecs: &mut World, tile_idx: usize, effect: &EffectSpawner) {
+pub fn particle_to_tile(ecs: &mut World, effect: &EffectSpawner, tile_idx: usize) {
     if let EffectType::Particle(glyph, color, lifespan) = effect.effect_type {
         let map = ecs.fetch::<Map>();
         let mut builder = ecs.fetch_mut::<ParticleBuilder>();
@@ -8,26 +8,28 @@ pub fn particle_to_tile(ecs: &mut World, tile_idx: usize, effect: &EffectSpawner
     }
 }
 
-pub fn add_damage_particle(target: Entity) {
-    add_effect(
-        None,
-        EffectType::Particle(to_cp437('‼'), ColorPair::new(ORANGE, BLACK), 200.0),
-        Targets::Single(target),
-    );
-}
+impl EffectQueue {
+    pub fn add_damage_particle(&mut self, target: Entity) {
+        self.add_effect(
+            None,
+            EffectType::Particle(to_cp437('‼'), ColorPair::new(ORANGE, BLACK), 200.0),
+            Targets::Single(target),
+        );
+    }
 
-pub fn add_hit_miss_particle(target: Entity) {
-    add_effect(
-        None,
-        EffectType::Particle(to_cp437('‼'), ColorPair::new(CYAN, BLACK), 200.0),
-        Targets::Single(target),
-    );
-}
+    pub fn add_hit_miss_particle(&mut self, target: Entity) {
+        self.add_effect(
+            None,
+            EffectType::Particle(to_cp437('‼'), ColorPair::new(CYAN, BLACK), 200.0),
+            Targets::Single(target),
+        );
+    }
 
-pub fn add_heal_particle(target: Entity) {
-    add_effect(
-        None,
-        EffectType::Particle(to_cp437('‼'), ColorPair::new(GREEN, BLACK), 200.0),
-        Targets::Single(target),
-    );
+    pub fn add_heal_particle(&mut self, target: Entity) {
+        self.add_effect(
+            None,
+            EffectType::Particle(to_cp437('‼'), ColorPair::new(GREEN, BLACK), 200.0),
+            Targets::Single(target),
+        );
+    }
 }
@@ -0,0 +1,202 @@
+use super::*;
+use std::collections::HashMap;
+
+/// Applies an effect to all of its targets at once
+pub type EffectHandler = fn(&mut World, &EffectSpawner);
+/// Applies an effect to one entity
+pub type EntityEffectHandler = fn(&mut World, &EffectSpawner, Entity);
+/// Applies an effect to one map tile
+pub type TileEffectHandler = fn(&mut World, &EffectSpawner, usize);
+/// Hears about everything that happens as effects are applied
+pub type EffectObserver = fn(&mut World, &EffectEvent);
+
+/// Which kind of effect an `EffectType` is, regardless of its payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EffectKind {
+    Feed,
+    EntityDeath,
+    Bloodstain,
+    Damage,
+    Healing,
+    RestoreMana,
+    Status,
+    ItemUse,
+    SpellUse,
+    TriggerFire,
+    Particle,
+    Teleport,
+    Blink,
+    Knockback,
+    Pull,
+    SwapPlaces,
+    Hazard,
+    Douse,
+}
+
+impl EffectType {
+    pub fn kind(&self) -> EffectKind {
+        match self {
+            EffectType::Feed(_) => EffectKind::Feed,
+            EffectType::EntityDeath => EffectKind::EntityDeath,
+            EffectType::Bloodstain(_) => EffectKind::Bloodstain,
+            EffectType::Damage(..) => EffectKind::Damage,
+            EffectType::Healing(_) => EffectKind::Healing,
+            EffectType::RestoreMana(_) => EffectKind::RestoreMana,
+            EffectType::Status(..) => EffectKind::Status,
+            EffectType::ItemUse(_) => EffectKind::ItemUse,
+            EffectType::SpellUse(_) => EffectKind::SpellUse,
+            EffectType::TriggerFire(_) => EffectKind::TriggerFire,
+            EffectType::Particle(..) => EffectKind::Particle,
+            EffectType::Teleport(_) => EffectKind::Teleport,
+            EffectType::Blink(_) => EffectKind::Blink,
+            EffectType::Knockback(_) => EffectKind::Knockback,
+            EffectType::Pull(_) => EffectKind::Pull,
+            EffectType::SwapPlaces => EffectKind::SwapPlaces,
+            EffectType::Hazard(..) => EffectKind::Hazard,
+            EffectType::Douse => EffectKind::Douse,
+        }
+    }
+}
+
+/// Something an effect did, once it's been done
+#[derive(Debug, Clone, Copy)]
+pub enum EffectEvent {
+    DamageTaken { source: Option<Entity>, target: Entity, amount: i32, damage_type: DamageType },
+    Killed { killer: Option<Entity>, victim: Entity },
+    Healed { source: Option<Entity>, target: Entity, amount: i32 },
+}
+
+/// Who handles each kind of effect, and who is watching. Registering a handler for a kind replaces
+/// whatever handled it before; observers only ever add up.
+#[derive(Default)]
+pub struct EffectRegistry {
+    effect_handlers: HashMap<EffectKind, EffectHandler>,
+    entity_handlers: HashMap<EffectKind, EntityEffectHandler>,
+    tile_handlers: HashMap<EffectKind, TileEffectHandler>,
+    observers: Vec<EffectObserver>,
+}
+
+impl EffectRegistry {
+    /// The registry with every built-in effect and observer in place
+    pub fn new() -> Self {
+        let mut registry = Self::default();
+
+        registry.on_effect(EffectKind::ItemUse, triggers::item_trigger);
+        registry.on_effect(EffectKind::SpellUse, triggers::spell_trigger);
+        registry.on_effect(EffectKind::TriggerFire, triggers::trigger);
+
+        registry.on_entity(EffectKind::Feed, hunger::feed);
+        registry.on_entity(EffectKind::EntityDeath, damage::death);
+        registry.on_entity(EffectKind::Healing, damage::heal_damage);
+        registry.on_entity(EffectKind::RestoreMana, damage::restore_mana);
+        registry.on_entity(EffectKind::Damage, damage::inflict_damage);
+        registry.on_entity(EffectKind::Status, status::apply_status);
+        registry.on_entity(EffectKind::Teleport, movement::teleport);
+        registry.on_entity(EffectKind::Blink, movement::blink);
+        registry.on_entity(EffectKind::Knockback, movement::knockback);
+        registry.on_entity(EffectKind::Pull, movement::pull);
+        registry.on_entity(EffectKind::SwapPlaces, movement::swap_places);
+
+        registry.on_tile(EffectKind::Bloodstain, damage::bloodstain);
+        registry.on_tile(EffectKind::Particle, particles::particle_to_tile);
+        registry.on_tile(EffectKind::Hazard, hazards::create_hazard);
+        registry.on_tile(EffectKind::Douse, hazards::douse);
+
+        registry.observe(damage::record_combat_events);
+        registry.observe(damage::award_experience);
+
+        registry
+    }
+
+    pub fn on_effect(&mut self, kind: EffectKind, handler: EffectHandler) {
+        self.effect_handlers.insert(kind, handler);
+    }
+
+    pub fn on_entity(&mut self, kind: EffectKind, handler: EntityEffectHandler) {
+        self.entity_handlers.insert(kind, handler);
+    }
+
+    pub fn on_tile(&mut self, kind: EffectKind, handler: TileEffectHandler) {
+        self.tile_handlers.insert(kind, handler);
+    }
+
+    pub fn observe(&mut self, observer: EffectObserver) { self.observers.push(observer); }
+
+    pub fn effect_handler(&self, kind: EffectKind) -> Option<EffectHandler> {
+        self.effect_handlers.get(&kind).copied()
+    }
+
+    pub fn entity_handler(&self, kind: EffectKind) -> Option<EntityEffectHandler> {
+        self.entity_handlers.get(&kind).copied()
+    }
+
+    pub fn tile_handler(&self, kind: EffectKind) -> Option<TileEffectHandler> {
+        self.tile_handlers.get(&kind).copied()
+    }
+}
+
+/// Tells every observer what just happened
+pub fn notify(ecs: &mut World, event: EffectEvent) {
+    let observers = ecs.fetch::<EffectRegistry>().observers.clone();
+    for observer in observers {
+        observer(ecs, &event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Heard(Vec<EffectEvent>);
+
+    fn listen(ecs: &mut World, event: &EffectEvent) { ecs.write_resource::<Heard>().0.push(*event); }
+
+    #[test]
+    fn observers_hear_damage_and_kills() {
+        let mut world = World::new();
+        world.register::<CombatStats>();
+        world.register::<Resistances>();
+        world.register::<Equipped>();
+        world.register::<Name>();
+        world.register::<Blood>();
+        world.register::<Point>();
+
+        let mut registry = EffectRegistry::default();
+        registry.on_entity(EffectKind::Damage, damage::inflict_damage);
+        registry.on_entity(EffectKind::EntityDeath, damage::death);
+        registry.observe(listen);
+
+        world.insert(registry);
+        world.insert(EffectQueue::new());
+        world.insert(Heard::default());
+
+        let attacker = world.create_entity().build();
+        let victim = world.create_entity().with(CombatStats::new(5, 5, 0, 0)).build();
+        world.insert(attacker);
+
+        for _ in 0..2 {
+            add_effect(
+                &world,
+                Some(attacker),
+                EffectType::Damage(3, DamageType::Fire),
+                Targets::Single(victim),
+            );
+            run_effects_queue(&mut world);
+        }
+
+        let heard = &world.fetch::<Heard>().0;
+        assert_eq!(heard.len(), 3);
+        for event in &heard[..2] {
+            assert!(matches!(
+                *event,
+                EffectEvent::DamageTaken { source: Some(source), target, amount: 3, damage_type: DamageType::Fire }
+                    if source == attacker && target == victim
+            ));
+        }
+        assert!(matches!(
+            heard[2],
+            EffectEvent::Killed { killer: Some(killer), victim: dead } if killer == attacker && dead == victim
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+pub fn apply_status(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
+    if let EffectType::Status(kind, turns, magnitude) = effect.effect_type {
+        // Only things that can fight can be afflicted
+        if !ecs.read_storage::<CombatStats>().contains(target) {
+            return;
+        }
+
+        let is_new = {
+            let mut statuses = ecs.write_storage::<StatusEffects>();
+            let statuses =
+                statuses.entry(target).expect("Entity is dead").or_insert_with(StatusEffects::default);
+            statuses.apply(kind, turns, magnitude)
+        };
+
+        if !is_new {
+            return;
+        }
+
+        // On-apply hook
+        if let Some(name) = ecs.read_storage::<Name>().get(target) {
+            bo_logging::Logger::new()
+                .npc_name(&name.0)
+                .append_with_color(kind.apply_message(), kind.color())
+                .log();
+        }
+
+        refresh_after_status_change(ecs, kind, target);
+    }
+}
+
+/// Makes sure speed and sight catch up with a status starting or stopping
+pub fn refresh_after_status_change(ecs: &World, kind: StatusKind, target: Entity) {
+    if kind.changes_stats() {
+        ecs.write_storage::<StatsChanged>()
+            .insert(target, StatsChanged {})
+            .expect("Unable to insert marker");
+    }
+
+    if kind.changes_sight() {
+        if let Some(fov) = ecs.write_storage::<FieldOfView>().get_mut(target) {
+            fov.is_dirty = true;
+        }
+    }
+}
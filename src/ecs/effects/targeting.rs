@@ -1,5 +1,8 @@
 use super::*;
 
+/// Half the angle a cone spreads over, in degrees
+const CONE_HALF_ANGLE: f32 = 45.0;
+
 pub fn entity_position(ecs: &World, target: Entity) -> Option<usize> {
     if let Some(pos) = ecs.read_storage::<Point>().get(target) {
         let map = ecs.fetch::<Map>();
@@ -8,13 +11,194 @@ pub fn entity_position(ecs: &World, target: Entity) -> Option<usize> {
     None
 }
 
-pub fn aoe_tiles(map: &Map, target: Point, radius: i32) -> Vec<usize> {
-    let blast_tiles = field_of_view_set(target, radius, map);
-    let mut result = Vec::new();
+/// Every tile an area of effect covers when `origin` aims it at `target`. Traps go off with both
+/// at the trap, which sends cones out in every direction and lines along the more open axis.
+pub fn aoe_tiles(map: &Map, origin: Point, target: Point, aoe: &AreaOfEffect) -> Vec<usize> {
+    let tiles = match aoe.shape {
+        AoeShape::Circle => field_of_view(target, aoe.radius, map),
+        AoeShape::Ring => field_of_view(target, aoe.radius, map)
+            .into_iter()
+            .filter(|pt| DistanceAlg::Pythagoras.distance2d(target, *pt) > (aoe.radius - 1) as f32)
+            .collect(),
+        AoeShape::Cross => {
+            let mut tiles = vec![target];
+            for step in [Point::new(0, -1), Point::new(0, 1), Point::new(-1, 0), Point::new(1, 0)] {
+                tiles.extend(open_run(map, target, step, aoe.radius));
+            }
+            tiles
+        }
+        AoeShape::Cone if origin == target => field_of_view(target, aoe.radius, map),
+        AoeShape::Cone => cone(map, origin, target, aoe.radius),
+        AoeShape::Line if origin == target => {
+            let horizontal = [
+                open_run(map, target, Point::new(-1, 0), aoe.radius),
+                open_run(map, target, Point::new(1, 0), aoe.radius),
+            ];
+            let vertical = [
+                open_run(map, target, Point::new(0, -1), aoe.radius),
+                open_run(map, target, Point::new(0, 1), aoe.radius),
+            ];
+            let run_length = |run: &[Vec<Point>; 2]| run[0].len() + run[1].len();
+
+            let mut tiles = vec![target];
+            let runs = if run_length(&vertical) > run_length(&horizontal) { vertical } else { horizontal };
+            runs.into_iter().for_each(|run| tiles.extend(run));
+            tiles
+        }
+        AoeShape::Line => beam(map, origin, target, aoe.radius),
+    };
+
+    tiles.iter().filter(|pt| map.in_bounds(**pt)).map(|pt| map.point2d_to_index(*pt)).collect()
+}
+
+/// Steps out from `start` up to `length` tiles, stopping short of the first wall
+fn open_run(map: &Map, start: Point, step: Point, length: i32) -> Vec<Point> {
+    (1..=length)
+        .map(|i| start + step * i)
+        .take_while(|pt| map.in_bounds(*pt) && !map.tiles[map.point2d_to_index(*pt)].opaque)
+        .collect()
+}
+
+/// From next to `origin`, through `target` and on until it is `length` tiles long or hits a wall
+fn beam(map: &Map, origin: Point, target: Point, length: i32) -> Vec<Point> {
+    let delta = target - origin;
+    let steps = i32::max(delta.x.abs(), delta.y.abs());
+
+    // Aiming at a whole number of `delta`s keeps the line passing exactly through `target`, which
+    // scaling `delta` down to `length` can round away from
+    let end = origin + delta * ((length + steps - 1) / steps);
+
+    line2d_bresenham(origin, end)
+        .into_iter()
+        .filter(|pt| *pt != origin)
+        .take(length as usize)
+        .take_while(|pt| map.in_bounds(*pt) && !map.tiles[map.point2d_to_index(*pt)].opaque)
+        .collect()
+}
+
+fn cone(map: &Map, origin: Point, target: Point, length: i32) -> Vec<Point> {
+    let aim = (target.y - origin.y) as f32;
+    let aim = aim.atan2((target.x - origin.x) as f32);
+
+    field_of_view(origin, length, map)
+        .into_iter()
+        .filter(|pt| *pt != origin)
+        .filter(|pt| {
+            let angle = ((pt.y - origin.y) as f32).atan2((pt.x - origin.x) as f32);
+            let mut spread = (angle - aim).abs().to_degrees();
+            if spread > 180.0 {
+                spread = 360.0 - spread;
+            }
+            spread <= CONE_HALF_ANGLE
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn covered(map: &Map, origin: Point, target: Point, shape: AoeShape, radius: i32) -> HashSet<Point> {
+        aoe_tiles(map, origin, target, &AreaOfEffect::new(shape, radius))
+            .into_iter()
+            .map(|idx| map.index_to_point2d(idx))
+            .collect()
+    }
+
+    fn row(xs: std::ops::RangeInclusive<i32>, y: i32) -> HashSet<Point> {
+        xs.map(|x| Point::new(x, y)).collect()
+    }
+
+    #[test]
+    fn circle_covers_everything_within_its_radius() {
+        let _lock = crate::spatial::TEST_LOCK.lock();
+        let map = Map::test_room(11, 11);
+        let centre = Point::new(5, 5);
 
-    for t in blast_tiles.iter() {
-        result.push(map.point2d_to_index(*t));
+        let tiles = covered(&map, Point::new(1, 1), centre, AoeShape::Circle, 2);
+        assert!(tiles.contains(&centre));
+        assert!(tiles.contains(&Point::new(5, 3)) && tiles.contains(&Point::new(7, 5)));
+        assert!(!tiles.contains(&Point::new(7, 7)));
     }
 
-    result
+    #[test]
+    fn ring_spares_the_middle() {
+        let _lock = crate::spatial::TEST_LOCK.lock();
+        let map = Map::test_room(11, 11);
+        let centre = Point::new(5, 5);
+
+        let tiles = covered(&map, Point::new(1, 1), centre, AoeShape::Ring, 3);
+        assert!(tiles.contains(&Point::new(5, 2)) && tiles.contains(&Point::new(8, 5)));
+        assert!(tiles.iter().all(|pt| DistanceAlg::Pythagoras.distance2d(centre, *pt) > 2.0));
+    }
+
+    #[test]
+    fn cross_arms_stop_at_walls() {
+        let _lock = crate::spatial::TEST_LOCK.lock();
+        let map = Map::test_room(11, 11);
+
+        let tiles = covered(&map, Point::new(1, 1), Point::new(2, 5), AoeShape::Cross, 3);
+        let mut expected = row(1..=5, 5);
+        expected.extend((2..=8).map(|y| Point::new(2, y)));
+        assert_eq!(tiles, expected);
+    }
+
+    #[test]
+    fn line_passes_through_the_target_at_any_angle() {
+        let _lock = crate::spatial::TEST_LOCK.lock();
+        let map = Map::test_room(21, 21);
+        let origin = Point::new(10, 10);
+
+        // Scaling (4, 3) up to 5 tiles rounds to (5, 3), whose line passes (4, 2) and misses
+        for (target, length) in [
+            (Point::new(14, 13), 5),
+            (Point::new(6, 7), 5),
+            (Point::new(13, 11), 8),
+            (Point::new(11, 13), 8),
+            (Point::new(12, 10), 8),
+        ] {
+            let tiles = covered(&map, origin, target, AoeShape::Line, length);
+            assert!(tiles.contains(&target), "line towards {:?} missed it", target);
+            assert!(!tiles.contains(&origin));
+            assert_eq!(tiles.len(), length as usize, "line towards {:?} is the wrong length", target);
+        }
+    }
+
+    #[test]
+    fn line_stops_at_the_first_wall() {
+        let _lock = crate::spatial::TEST_LOCK.lock();
+        let map = Map::test_room(11, 11);
+
+        let tiles = covered(&map, Point::new(2, 5), Point::new(3, 5), AoeShape::Line, 20);
+        assert_eq!(tiles, row(3..=9, 5));
+    }
+
+    #[test]
+    fn cone_aimed_west_wraps_around_the_half_turn() {
+        let _lock = crate::spatial::TEST_LOCK.lock();
+        let map = Map::test_room(11, 11);
+        let origin = Point::new(5, 5);
+
+        let tiles = covered(&map, origin, Point::new(2, 5), AoeShape::Cone, 3);
+        assert!(tiles.contains(&Point::new(2, 5)));
+        assert!(tiles.contains(&Point::new(3, 4)) && tiles.contains(&Point::new(3, 6)));
+        assert!(!tiles.contains(&origin));
+        assert!(tiles.iter().all(|pt| pt.x < origin.x));
+    }
+
+    #[test]
+    fn traps_aimed_at_their_own_tile() {
+        let _lock = crate::spatial::TEST_LOCK.lock();
+        let map = Map::test_room(11, 5);
+        let trap = Point::new(5, 2);
+
+        // Cones burst out in every direction, like a circle
+        let cone = covered(&map, trap, trap, AoeShape::Cone, 2);
+        assert_eq!(cone, covered(&map, trap, trap, AoeShape::Circle, 2));
+
+        // Lines run along whichever axis is more open
+        let line = covered(&map, trap, trap, AoeShape::Line, 3);
+        assert_eq!(line, row(2..=8, 2));
+    }
 }
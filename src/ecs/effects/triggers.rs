@@ -1,16 +1,61 @@
 use super::*;
 
-pub fn item_trigger(creator: Option<Entity>, item: Entity, targets: &Targets, ecs: &mut World) {
+pub fn item_trigger(ecs: &mut World, effect: &EffectSpawner) {
+    let (creator, targets) = (effect.creator, &effect.targets);
+    let item = match effect.effect_type {
+        EffectType::ItemUse(item) => item,
+        _ => return,
+    };
+
     // Use the item via the generic system
     let did_something = event_trigger(creator, item, targets, ecs);
 
     // If it was a consumable, then it gets deleted
-    if did_something && ecs.read_storage::<Consumable>().get(item).is_some() {
+    let mut used_up = did_something && ecs.read_storage::<Consumable>().get(item).is_some();
+
+    // If it has charges, one of them is spent
+    if did_something {
+        if let Some(charges) = ecs.write_storage::<Charges>().get_mut(item) {
+            charges.remaining -= 1;
+            if charges.remaining < 1 {
+                let item_name = get_item_display_name(ecs, item);
+                if charges.crumbles {
+                    used_up = true;
+                    bo_logging::Logger::new()
+                        .append("The")
+                        .item_name(item_name)
+                        .append("crumbles to dust.")
+                        .log();
+                } else {
+                    bo_logging::Logger::new()
+                        .append("The")
+                        .item_name(item_name)
+                        .append("falls dormant.")
+                        .log();
+                }
+            }
+        }
+    }
+
+    if used_up {
         ecs.entities().delete(item).expect("Delete Failed");
+
+        // The user's load just got lighter
+        if let Some(user) = creator {
+            ecs.write_storage::<StatsChanged>()
+                .insert(user, StatsChanged {})
+                .expect("Unable to insert marker");
+        }
     }
 }
 
-pub fn trigger(creator: Option<Entity>, trigger: Entity, targets: &Targets, ecs: &mut World) {
+pub fn trigger(ecs: &mut World, effect: &EffectSpawner) {
+    let (creator, targets) = (effect.creator, &effect.targets);
+    let trigger = match effect.effect_type {
+        EffectType::TriggerFire(trigger) => trigger,
+        _ => return,
+    };
+
     // The triggering item is no longer hidden
     ecs.write_storage::<Hidden>().remove(trigger);
 
@@ -23,33 +68,141 @@ pub fn trigger(creator: Option<Entity>, trigger: Entity, targets: &Targets, ecs:
     }
 }
 
+pub fn spell_trigger(ecs: &mut World, effect: &EffectSpawner) {
+    // Spells work just like items, only they're never used up
+    if let EffectType::SpellUse(spell) = effect.effect_type {
+        event_trigger(effect.creator, spell, &effect.targets, ecs);
+    }
+}
+
 fn event_trigger(creator: Option<Entity>, entity: Entity, targets: &Targets, ecs: &mut World) -> bool {
     let mut did_something = false;
 
     // Providing food
-    if ecs.read_storage::<ProvidesFood>().get(entity).is_some() {
+    if let Some(food) = ecs.read_storage::<ProvidesFood>().get(entity) {
         did_something = true;
-        let names = ecs.read_storage::<Name>();
-
-        add_effect(creator, EffectType::WellFed, targets.clone());
-        bo_logging::Logger::new().append("You eat the").item_name(&names.get(entity).unwrap().0).log();
+        add_effect(ecs, creator, EffectType::Feed(food.0), targets.clone());
+        bo_logging::Logger::new().append("You eat the").item_name(get_item_display_name(ecs, entity)).log();
     }
 
     // Healing
     if let Some(heal) = ecs.read_storage::<ProvidesHealing>().get(entity) {
-        add_effect(creator, EffectType::Healing(heal.0), targets.clone());
+        add_effect(ecs, creator, EffectType::Healing(heal.0), targets.clone());
+        did_something = true;
+    }
+
+    // Mana
+    if let Some(mana) = ecs.read_storage::<ProvidesMana>().get(entity) {
+        add_effect(ecs, creator, EffectType::RestoreMana(mana.0), targets.clone());
         did_something = true;
     }
 
     // Damage
     if let Some(damage) = ecs.read_storage::<InflictsDamage>().get(entity) {
-        add_effect(creator, EffectType::Damage(damage.0), targets.clone());
+        if ecs.read_storage::<Name>().contains(entity) {
+            bo_logging::Logger::new()
+                .append("The")
+                .item_name(get_item_display_name(ecs, entity))
+                .append("deals")
+                .damage(damage.amount)
+                .color(damage.damage_type.color())
+                .append(damage.damage_type.name())
+                .color(WHITE)
+                .append("damage.")
+                .log();
+        }
+
+        add_effect(ecs, creator, EffectType::Damage(damage.amount, damage.damage_type), targets.clone());
+        did_something = true;
+    }
+
+    // Statuses
+    if let Some(inflicts) = ecs.read_storage::<InflictsStatus>().get(entity) {
+        for (kind, magnitude) in inflicts.statuses.iter() {
+            add_effect(ecs, creator, EffectType::Status(*kind, inflicts.turns, *magnitude), targets.clone());
+        }
+        did_something = true;
+    }
+
+    // Teleporting whoever is hit
+    if ecs.read_storage::<Teleports>().contains(entity) {
+        add_effect(ecs, creator, EffectType::Teleport(None), targets.clone());
         did_something = true;
     }
 
-    // Confusion
-    if let Some(confusion) = ecs.read_storage::<Confusion>().get(entity) {
-        add_effect(creator, EffectType::Confusion(confusion.turns), targets.clone());
+    // Teleporting the user to where it was aimed
+    if ecs.read_storage::<TeleportsToTarget>().contains(entity) {
+        if let (Some(user), Targets::Tile(tile_idx)) = (creator, targets) {
+            let destination = ecs.fetch::<Map>().index_to_point2d(*tile_idx);
+            add_effect(ecs, creator, EffectType::Teleport(Some(destination)), Targets::Single(user));
+            did_something = true;
+        }
+    }
+
+    // Blinking
+    if let Some(blinks) = ecs.read_storage::<Blinks>().get(entity) {
+        add_effect(ecs, creator, EffectType::Blink(blinks.0), targets.clone());
+        did_something = true;
+    }
+
+    // Knockback
+    if let Some(knocks_back) = ecs.read_storage::<KnocksBack>().get(entity) {
+        add_effect(ecs, creator, EffectType::Knockback(knocks_back.0), targets.clone());
+        did_something = true;
+    }
+
+    // Pulling
+    if let Some(pulls) = ecs.read_storage::<Pulls>().get(entity) {
+        add_effect(ecs, creator, EffectType::Pull(pulls.0), targets.clone());
+        did_something = true;
+    }
+
+    // Swapping places
+    if ecs.read_storage::<SwapsPlaces>().contains(entity) {
+        add_effect(ecs, creator, EffectType::SwapPlaces, targets.clone());
+        did_something = true;
+    }
+
+    // Hazards
+    if let Some(hazard) = ecs.read_storage::<CreatesHazard>().get(entity) {
+        add_effect(ecs, creator, EffectType::Hazard(hazard.kind, hazard.turns), targets.clone());
+        did_something = true;
+    }
+
+    // Putting out fires
+    if ecs.read_storage::<Douses>().contains(entity) {
+        add_effect(ecs, creator, EffectType::Douse, targets.clone());
+        did_something = true;
+    }
+
+    // Identification
+    if ecs.read_storage::<ProvidesIdentification>().contains(entity) {
+        if let Some(user) = creator {
+            identify_carried(ecs, user);
+        }
+        did_something = true;
+    }
+
+    // Remove curse
+    if ecs.read_storage::<ProvidesRemoveCurse>().contains(entity) {
+        if let Some(user) = creator {
+            remove_curses(ecs, user);
+        }
+        did_something = true;
+    }
+
+    // Recharge
+    if ecs.read_storage::<ProvidesRecharge>().contains(entity) {
+        if let Some(user) = creator {
+            recharge_carried(ecs, user);
+        }
+        did_something = true;
+    }
+
+    // Learning a spell
+    let teaches = ecs.read_storage::<TeachesSpell>().get(entity).map(|teaches| teaches.0.clone());
+    if let (Some(spell_name), Some(user)) = (teaches, creator) {
+        learn_spell(ecs, user, &spell_name);
         did_something = true;
     }
 
@@ -63,3 +216,128 @@ fn event_trigger(creator: Option<Entity>, entity: Entity, targets: &Targets, ecs
 
     did_something
 }
+
+/// Everything `user` is carrying or wearing
+fn carried_by(ecs: &World, user: Entity) -> Vec<Entity> {
+    let entities = ecs.entities();
+    let backpack = ecs.read_storage::<InBackpack>();
+    let equipped = ecs.read_storage::<Equipped>();
+
+    (&entities)
+        .join()
+        .filter(|item| {
+            backpack.get(*item).is_some_and(|pack| pack.owner == user)
+                || equipped.get(*item).is_some_and(|equip| equip.owner == user)
+        })
+        .collect()
+}
+
+/// Reveals the true name, and any curse, of everything `user` is carrying or wearing
+fn identify_carried(ecs: &World, user: Entity) {
+    let names = ecs.read_storage::<Name>();
+    let mut cursed = ecs.write_storage::<Cursed>();
+    let mut dm = ecs.fetch_mut::<MasterDungeonMap>();
+
+    let mut identified = false;
+    for item in carried_by(ecs, user) {
+        let name = match names.get(item) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        if !dm.is_identified(&name.0) {
+            let masked_name = dm.display_name(&name.0);
+            dm.identify(&name.0);
+            identified = true;
+
+            bo_logging::Logger::new()
+                .append("The")
+                .item_name(masked_name)
+                .append("is a")
+                .item_name(&name.0)
+                .log();
+        }
+
+        if let Some(curse) = cursed.get_mut(item).filter(|curse| !curse.known) {
+            curse.known = true;
+            identified = true;
+
+            bo_logging::Logger::new().append("The").item_name(&name.0).append("is cursed!").log();
+        }
+    }
+
+    if !identified {
+        bo_logging::Logger::new().append("You have nothing left to identify.").log();
+    }
+}
+
+/// Lifts the curse from everything `user` is carrying or wearing
+fn remove_curses(ecs: &World, user: Entity) {
+    let names = ecs.read_storage::<Name>();
+    let mut cursed = ecs.write_storage::<Cursed>();
+    let dm = ecs.fetch::<MasterDungeonMap>();
+
+    let mut lifted = false;
+    for item in carried_by(ecs, user) {
+        if cursed.remove(item).is_none() {
+            continue;
+        }
+
+        lifted = true;
+        if let Some(name) = names.get(item) {
+            bo_logging::Logger::new()
+                .append("The")
+                .item_name(dm.display_name(&name.0))
+                .append("is no longer cursed.")
+                .log();
+        }
+    }
+
+    if !lifted {
+        bo_logging::Logger::new().append("You feel a brief tingle, but nothing happens.").log();
+    }
+}
+
+/// Restores the charges of everything `user` is carrying or wearing
+fn recharge_carried(ecs: &World, user: Entity) {
+    let mut recharged = false;
+    for item in carried_by(ecs, user) {
+        let refilled = match ecs.write_storage::<Charges>().get_mut(item) {
+            Some(charges) if charges.remaining < charges.max => {
+                charges.remaining = charges.max;
+                true
+            }
+            _ => false,
+        };
+
+        if refilled {
+            recharged = true;
+            bo_logging::Logger::new()
+                .append("The")
+                .item_name(get_item_display_name(ecs, item))
+                .append("hums with renewed power.")
+                .log();
+        }
+    }
+
+    if !recharged {
+        bo_logging::Logger::new().append("You feel a brief surge of power, but nothing happens.").log();
+    }
+}
+
+fn learn_spell(ecs: &mut World, user: Entity, spell_name: &str) {
+    let already_known = {
+        let names = ecs.read_storage::<Name>();
+        let spells = ecs.read_storage::<Spell>();
+        (&spells, &names).join().any(|(spell, name)| spell.owner == user && name.0 == spell_name)
+    };
+
+    if already_known {
+        bo_logging::Logger::new().append("You already know").item_name(spell_name).log();
+        return;
+    }
+
+    if raws::spawn_named_spell(&RAWS.lock(), ecs, spell_name, user).is_some() {
+        bo_logging::Logger::new().color(MAGENTA).append("You learn").item_name(spell_name).log();
+    }
+}
@@ -1,44 +1,74 @@
-use crate::prelude::*;
+use super::*;
 
 pub struct BystanderAI {}
 
 impl<'a> System<'a> for BystanderAI {
+    #[allow(clippy::type_complexity)]
     type SystemData = (
         Entities<'a>,
         WriteExpect<'a, Map>,
-        ReadExpect<'a, TurnState>,
+        ReadStorage<'a, MyTurn>,
         WriteStorage<'a, Point>,
         WriteStorage<'a, FieldOfView>,
         WriteStorage<'a, EntityMoved>,
         ReadStorage<'a, Bystander>,
+        ReadStorage<'a, Faction>,
+        ReadStorage<'a, CombatStats>,
+        ReadStorage<'a, StatusEffects>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (entities, map, turn_state, mut points, mut fov, mut entity_moved, bystander) = data;
+        let (
+            entities,
+            map,
+            turns,
+            mut points,
+            mut fov,
+            mut entity_moved,
+            bystander,
+            factions,
+            combat_stats,
+            status_effects,
+        ) = data;
 
-        if *turn_state != TurnState::MonsterTurn {
-            return;
-        }
+        for (entity, _turn, fov, _bystander, pos) in
+            (&entities, &turns, &mut fov, &bystander, &mut points).join()
+        {
+            if status_effects.get(entity).is_some_and(|statuses| statuses.prevents_action()) {
+                continue;
+            }
 
-        for (entity, mut fov, _bystander, pos) in (&entities, &mut fov, &bystander, &mut points).join() {
-            // Try to move randomly
-            let destination = match crate::rng::range(0, 4) {
-                0 => Point::new(-1, 0),
-                1 => Point::new(1, 0),
-                2 => Point::new(0, -1),
-                _ => Point::new(0, 1),
-            } + *pos;
+            // Run from anything hostile, otherwise try to move randomly
+            let threat = factions.get(entity).and_then(|faction| {
+                find_nearest_hostile(entity, faction, *pos, fov, &factions, &combat_stats)
+            });
 
-            if map.can_enter_tile(destination) {
-                *pos = destination;
-                fov.is_dirty = true;
-                entity_moved.insert(entity, EntityMoved {}).expect("Unable to insert marker");
+            let destination = if let Some((_, threat_pos)) = threat {
+                match step_away(&map, *pos, threat_pos) {
+                    Some(destination) => destination,
+                    None => continue,
+                }
+            } else {
+                let delta = match crate::rng::range(0, 4) {
+                    0 => Point::new(-1, 0),
+                    1 => Point::new(1, 0),
+                    2 => Point::new(0, -1),
+                    _ => Point::new(0, 1),
+                };
 
+                delta + *pos
+            };
+
+            if map.can_enter_tile(destination) {
                 crate::spatial::move_entity(
                     entity,
                     map.point2d_to_index(*pos),
                     map.point2d_to_index(destination),
                 );
+
+                *pos = destination;
+                fov.is_dirty = true;
+                entity_moved.insert(entity, EntityMoved {}).expect("Unable to insert marker");
             }
         }
     }
@@ -1,5 +1,85 @@
+use crate::prelude::*;
+
 mod bystander_ai;
 mod monster_ai;
 
 pub use bystander_ai::*;
 pub use monster_ai::*;
+
+/// Finds the closest living entity in view that `faction` is hostile towards
+pub fn find_nearest_hostile(
+    entity: Entity,
+    faction: &Faction,
+    pos: Point,
+    fov: &FieldOfView,
+    factions: &ReadStorage<Faction>,
+    combat_stats: &ReadStorage<CombatStats>,
+) -> Option<(Entity, Point)> {
+    let raws = RAWS.lock();
+    let mut nearest: Option<(Entity, Point, f32)> = None;
+
+    for tile in fov.visible_tiles.iter() {
+        crate::spatial::for_each_tile_content_pt(*tile, |other| {
+            if other == entity || combat_stats.get(other).is_none_or(|stats| stats.hp < 1) {
+                return;
+            }
+
+            if let Some(their_faction) = factions.get(other) {
+                if raws::faction_reaction(&faction.0, &their_faction.0, &raws) == Reaction::Hostile {
+                    let distance = DistanceAlg::Pythagoras.distance2d(pos, *tile);
+                    if nearest.is_none_or(|(_, _, d)| distance < d) {
+                        nearest = Some((other, *tile, distance));
+                    }
+                }
+            }
+        });
+    }
+
+    nearest.map(|(other, pt, _)| (other, pt))
+}
+
+/// Returns the next step along the path from `start` to `end`, if there is one
+pub fn step_towards(map: &Map, start: Point, end: Point) -> Option<Point> {
+    let path = a_star_search(map.point2d_to_index(start), map.point2d_to_index(end), map);
+
+    if path.success && path.steps.len() > 1 {
+        Some(map.index_to_point2d(path.steps[1]))
+    } else {
+        None
+    }
+}
+
+/// Returns a random orthogonal neighbour of `start`, if it can be entered
+pub fn random_step(map: &Map, start: Point) -> Option<Point> {
+    let destination = match crate::rng::range(0, 4) {
+        0 => Point::new(-1, 0),
+        1 => Point::new(1, 0),
+        2 => Point::new(0, -1),
+        _ => Point::new(0, 1),
+    } + start;
+
+    if map.can_enter_tile(destination) {
+        Some(destination)
+    } else {
+        None
+    }
+}
+
+/// Returns the neighbouring tile that puts the most distance between `start` and `threat`
+pub fn step_away(map: &Map, start: Point, threat: Point) -> Option<Point> {
+    let mut best = (start, DistanceAlg::Pythagoras.distance2d(start, threat));
+
+    for (idx, _) in map.get_available_exits(map.point2d_to_index(start)).iter() {
+        let candidate = map.index_to_point2d(*idx);
+        let distance = DistanceAlg::Pythagoras.distance2d(candidate, threat);
+        if distance > best.1 {
+            best = (candidate, distance);
+        }
+    }
+
+    if best.0 != start {
+        Some(best.0)
+    } else {
+        None
+    }
+}
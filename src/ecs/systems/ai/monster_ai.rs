@@ -1,4 +1,12 @@
-use crate::prelude::*;
+use super::*;
+use std::collections::HashMap;
+
+/// Below this percentage of their max hp, monsters will reach for a healing item
+const LOW_HEALTH: i32 = 50;
+
+/// Monsters with a ranged attack back away from anything within half their range, and always from
+/// anything in arm's reach
+const MIN_KEEP_DISTANCE: f32 = 1.5;
 
 pub struct MonsterAISystem {}
 
@@ -7,76 +15,257 @@ impl<'a> System<'a> for MonsterAISystem {
     type SystemData = (
         Entities<'a>,
         ReadExpect<'a, Map>,
-        ReadExpect<'a, Point>,
-        ReadExpect<'a, Entity>,
-        ReadExpect<'a, TurnState>,
+        ReadStorage<'a, MyTurn>,
         WriteStorage<'a, FieldOfView>,
         ReadStorage<'a, Monster>,
+        WriteStorage<'a, MonsterAI>,
+        ReadStorage<'a, Faction>,
+        ReadStorage<'a, Follower>,
+        ReadStorage<'a, CombatStats>,
         WriteStorage<'a, Point>,
         WriteStorage<'a, WantsToMelee>,
-        WriteStorage<'a, Confusion>,
+        WriteStorage<'a, WantsToShoot>,
+        WriteStorage<'a, RangedAttack>,
+        ReadStorage<'a, StatusEffects>,
         WriteStorage<'a, EntityMoved>,
+        ReadStorage<'a, InBackpack>,
+        ReadStorage<'a, ProvidesHealing>,
+        ReadStorage<'a, InflictsDamage>,
+        ReadStorage<'a, InflictsStatus>,
+        ReadStorage<'a, Ranged>,
+        ReadStorage<'a, AreaOfEffect>,
+        WriteStorage<'a, WantsToUseItem>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
         let (
             entities,
             map,
-            player_pos,
-            player_entity,
-            runstate,
+            turns,
             mut fov_storage,
             monster,
+            mut monster_ai,
+            factions,
+            followers,
+            combat_stats,
             mut position,
             mut wants_to_melee,
-            mut confused,
+            mut wants_to_shoot,
+            mut ranged_attacks,
+            status_effects,
             mut entity_moved,
+            backpack,
+            healing,
+            inflicts_damage,
+            inflicts_status,
+            ranged,
+            aoe,
+            mut wants_to_use,
         ) = data;
 
-        if *runstate != TurnState::MonsterTurn {
-            return;
+        // Pick the closest hostile each monster can see, before anyone moves
+        let targets: HashMap<Entity, (Entity, Point)> =
+            (&entities, &fov_storage, &monster, &factions, &position)
+                .join()
+                .filter_map(|(entity, fov, _monster, faction, pos)| {
+                    find_nearest_hostile(entity, faction, *pos, fov, &factions, &combat_stats)
+                        .map(|target| (entity, target))
+                })
+                .collect();
+
+        // Where each group leader is standing, so their followers can keep up
+        let leader_positions: HashMap<Entity, Point> = (&followers)
+            .join()
+            .filter_map(|follower| position.get(follower.leader).map(|pt| (follower.leader, *pt)))
+            .collect();
+
+        // What each monster is carrying
+        let mut carried: HashMap<Entity, Vec<Entity>> = HashMap::new();
+        for (item, pack) in (&entities, &backpack).join() {
+            carried.entry(pack.owner).or_default().push(item);
         }
 
-        for (entity, fov, _monster, pos) in (&entities, &mut fov_storage, &monster, &mut position).join() {
-            let can_act = if let Some(i_am_confused) = confused.get_mut(entity) {
-                i_am_confused.turns -= 1;
+        for (entity, _turn, fov, _monster, ai, stats, pos) in
+            (&entities, &turns, &mut fov_storage, &monster, &mut monster_ai, &combat_stats, &mut position)
+                .join()
+        {
+            if let Some(attack) = ranged_attacks.get_mut(entity) {
+                attack.cooldown_remaining = i32::max(0, attack.cooldown_remaining - 1);
+            }
 
-                if i_am_confused.turns < 1 {
-                    confused.remove(entity);
-                }
+            let statuses = status_effects.get(entity);
+            if statuses.is_some_and(|statuses| statuses.prevents_action()) {
+                continue;
+            }
+            let confused = statuses.is_some_and(|statuses| statuses.has(StatusKind::Confusion));
+
+            // Update our memory of the target before deciding what to do. Followers that can't see
+            // anything themselves go after whatever their leader is fighting.
+            let leader = followers.get(entity).map(|follower| follower.leader);
+            let target =
+                targets.get(&entity).or_else(|| leader.and_then(|leader| targets.get(&leader))).copied();
+            let leader_pos = leader.and_then(|leader| leader_positions.get(&leader)).copied();
+            let hp_percent = stats.hp * 100 / i32::max(stats.max_hp, 1);
+            if let Some((_, target_pos)) = target {
+                ai.last_seen = Some(target_pos);
 
-                false
+                ai.state = if ai.flee_below > 0 && hp_percent <= ai.flee_below {
+                    AIState::Flee
+                } else {
+                    AIState::Chase
+                };
+            } else if ai.state == AIState::Chase {
+                ai.state = AIState::Search;
+                ai.turns_searching = 0;
+            } else if ai.state == AIState::Flee {
+                ai.state = AIState::Return;
+            }
+
+            // Patch ourselves up, or throw something at the target, before closing in
+            let items = carried.get(&entity).map(|items| items.as_slice()).unwrap_or_default();
+            let potion = if hp_percent < LOW_HEALTH {
+                items.iter().find(|item| healing.contains(**item)).map(|item| (*item, None))
             } else {
-                true
+                None
             };
+            let item_use = potion.filter(|_| !confused).or_else(|| {
+                if confused {
+                    return None;
+                }
+
+                let (_, target_pos) = target?;
+                let distance = DistanceAlg::Pythagoras.distance2d(*pos, target_pos);
 
-            if can_act {
-                let distance = DistanceAlg::Pythagoras.distance2d(*pos, *player_pos);
-                if distance < 1.5 {
-                    wants_to_melee
-                        .insert(entity, WantsToMelee { target: *player_entity })
-                        .expect("Unable to insert attack");
-                } else if fov.visible_tiles.contains(&*player_pos) {
-                    let old_idx = map.point2d_to_index(*pos);
-                    let new_idx = map.point2d_to_index(*player_pos);
-
-                    // Path to the player
-                    let path = a_star_search(old_idx, new_idx, &*map);
-
-                    if path.success && path.steps.len() > 1 {
-                        let destination = map.index_to_point2d(path.steps[1]);
-
-                        crate::spatial::move_entity(
-                            entity,
-                            map.point2d_to_index(*pos),
-                            map.point2d_to_index(destination),
-                        );
-
-                        *pos = destination;
-                        fov.is_dirty = true;
-                        entity_moved.insert(entity, EntityMoved {}).expect("Unable to insert marker");
+                items
+                    .iter()
+                    .filter(|item| inflicts_damage.contains(**item) || inflicts_status.contains(**item))
+                    .find(|item| {
+                        // Don't catch ourselves in the blast
+                        let in_range = ranged.get(**item).is_some_and(|range| distance <= range.0 as f32);
+                        let clear_of_blast = aoe.get(**item).is_none_or(|aoe| {
+                            !aoe_tiles(&map, *pos, target_pos, aoe).contains(&map.point2d_to_index(*pos))
+                        });
+                        in_range && clear_of_blast
+                    })
+                    .map(|item| (*item, Some(target_pos)))
+            });
+
+            if let Some((item, target)) = item_use {
+                wants_to_use
+                    .insert(entity, WantsToUseItem::new(item, target))
+                    .expect("Unable to insert intent");
+                continue;
+            }
+
+            // Confused monsters stumble about instead of following the plan
+            let destination = if confused {
+                random_step(&map, *pos)
+            } else {
+                match ai.state {
+                    AIState::Idle | AIState::Wander | AIState::Return if leader_pos.is_some() => {
+                        ai.state = ai.default_state;
+
+                        // Stick with the leader rather than keeping to ourselves
+                        leader_pos
+                            .filter(|leader_pos| DistanceAlg::Pythagoras.distance2d(*pos, *leader_pos) > 2.0)
+                            .and_then(|leader_pos| step_towards(&map, *pos, leader_pos))
+                    }
+                    AIState::Idle => None,
+                    AIState::Wander => random_step(&map, *pos),
+                    AIState::Chase => match target {
+                        Some((target, target_pos)) => {
+                            let distance = DistanceAlg::Pythagoras.distance2d(*pos, target_pos);
+                            let can_shoot = ranged_attacks
+                                .get(entity)
+                                .is_some_and(|attack| attack.is_ready() && distance <= attack.range as f32);
+
+                            // Archers would rather keep their distance, even while they reload, until
+                            // they run out of shots
+                            let keep_distance = ranged_attacks
+                                .get(entity)
+                                .filter(|attack| attack.ammo.is_none_or(|ammo| ammo > 0))
+                                .map(|attack| f32::max(MIN_KEEP_DISTANCE, attack.range as f32 / 2.0));
+                            let retreat = if keep_distance.is_some_and(|keep| distance <= keep) {
+                                step_away(&map, *pos, target_pos)
+                            } else {
+                                None
+                            };
+
+                            if retreat.is_some() {
+                                retreat
+                            } else if can_shoot
+                                && line_of_fire(&map, *pos, target_pos).last() == Some(&target_pos)
+                            {
+                                wants_to_shoot
+                                    .insert(entity, WantsToShoot::new(target))
+                                    .expect("Unable to insert attack");
+                                None
+                            } else if distance < 1.5 {
+                                wants_to_melee
+                                    .insert(entity, WantsToMelee { target })
+                                    .expect("Unable to insert attack");
+                                None
+                            } else {
+                                step_towards(&map, *pos, target_pos)
+                            }
+                        }
+                        None => None,
+                    },
+                    AIState::Search => {
+                        ai.turns_searching += 1;
+
+                        let step = match ai.last_seen {
+                            Some(last_seen) if last_seen != *pos && ai.turns_searching <= ai.search_turns => {
+                                step_towards(&map, *pos, last_seen)
+                            }
+                            _ => None,
+                        };
+
+                        // Lost the trail, so give up and head home
+                        if step.is_none() {
+                            ai.last_seen = None;
+                            ai.state = AIState::Return;
+                        }
+
+                        step
+                    }
+                    AIState::Flee => match target {
+                        Some((target, target_pos)) => {
+                            let step = step_away(&map, *pos, target_pos);
+
+                            // Cornered, so fight back
+                            if step.is_none() && DistanceAlg::Pythagoras.distance2d(*pos, target_pos) < 1.5 {
+                                wants_to_melee
+                                    .insert(entity, WantsToMelee { target })
+                                    .expect("Unable to insert attack");
+                            }
+
+                            step
+                        }
+                        None => None,
+                    },
+                    AIState::Return => {
+                        let step = if *pos != ai.home { step_towards(&map, *pos, ai.home) } else { None };
+
+                        if step.is_none() {
+                            ai.state = ai.default_state;
+                        }
+
+                        step
                     }
                 }
+            };
+
+            if let Some(destination) = destination {
+                crate::spatial::move_entity(
+                    entity,
+                    map.point2d_to_index(*pos),
+                    map.point2d_to_index(destination),
+                );
+
+                *pos = destination;
+                fov.is_dirty = true;
+                entity_moved.insert(entity, EntityMoved {}).expect("Unable to insert marker");
             }
         }
     }
@@ -0,0 +1,139 @@
+use super::*;
+use std::collections::HashMap;
+
+/// Extra hit points for each point of fitness bonus
+const HP_PER_FITNESS: i32 = 3;
+/// Extra mana for each point of intelligence bonus
+const MANA_PER_INTELLIGENCE: i32 = 2;
+/// Pounds of gear that can be carried per point of might
+const CARRY_PER_MIGHT: i32 = 15;
+
+/// Recalculates attribute modifiers and everything derived from them, for anything marked with
+/// `StatsChanged`
+pub struct AttributeSystem {}
+
+impl<'a> System<'a> for AttributeSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        WriteStorage<'a, StatsChanged>,
+        WriteStorage<'a, Attributes>,
+        WriteStorage<'a, DerivedStats>,
+        WriteStorage<'a, CombatStats>,
+        WriteStorage<'a, ManaPool>,
+        WriteStorage<'a, Initiative>,
+        ReadStorage<'a, Equipped>,
+        ReadStorage<'a, AttributeBonus>,
+        ReadStorage<'a, StatusEffects>,
+        ReadStorage<'a, Item>,
+        ReadStorage<'a, InBackpack>,
+        ReadStorage<'a, Ammunition>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            player,
+            mut stats_changed,
+            mut attributes,
+            mut derived_stats,
+            mut combat_stats,
+            mut mana_pools,
+            mut initiatives,
+            equipped,
+            attribute_bonuses,
+            status_effects,
+            items,
+            backpack,
+            ammunition,
+        ) = data;
+
+        if stats_changed.is_empty() {
+            return;
+        }
+
+        // Total up what everyone's gear is doing to them
+        let mut gear_bonuses: HashMap<Entity, AttributeBonus> = HashMap::new();
+        for (equipped_by, bonus) in (&equipped, &attribute_bonuses).join() {
+            let total = gear_bonuses.entry(equipped_by.owner).or_default();
+            total.might = Some(total.might.unwrap_or(0) + bonus.might.unwrap_or(0));
+            total.fitness = Some(total.fitness.unwrap_or(0) + bonus.fitness.unwrap_or(0));
+            total.quickness = Some(total.quickness.unwrap_or(0) + bonus.quickness.unwrap_or(0));
+            total.intelligence = Some(total.intelligence.unwrap_or(0) + bonus.intelligence.unwrap_or(0));
+        }
+
+        // And how much it all weighs (joining on entities skips anything used up this turn)
+        let mut loads: HashMap<Entity, f32> = HashMap::new();
+        for (entity, item, equipped_by) in (&entities, &items, &equipped).join() {
+            *loads.entry(equipped_by.owner).or_default() += total_weight(item, ammunition.get(entity));
+        }
+        for (entity, item, carried_by) in (&entities, &items, &backpack).join() {
+            *loads.entry(carried_by.owner).or_default() += total_weight(item, ammunition.get(entity));
+        }
+
+        for (entity, _changed, attr, derived) in
+            (&entities, &stats_changed, &mut attributes, &mut derived_stats).join()
+        {
+            let gear = gear_bonuses.remove(&entity).unwrap_or_default();
+            for (attribute, modifier) in [
+                (&mut attr.might, gear.might),
+                (&mut attr.fitness, gear.fitness),
+                (&mut attr.quickness, gear.quickness),
+                (&mut attr.intelligence, gear.intelligence),
+            ] {
+                attribute.modifiers = modifier.unwrap_or(0);
+                attribute.bonus = attr_bonus(attribute.value());
+            }
+
+            derived.to_hit = attr.quickness.bonus;
+            derived.melee_damage = attr.might.bonus;
+            derived.carry_capacity = i32::max(0, attr.might.value() * CARRY_PER_MIGHT);
+            derived.carried_weight = loads.remove(&entity).unwrap_or(0.0);
+
+            let encumbrance = Encumbrance::from_weight(derived.carried_weight, derived.carry_capacity);
+            if entity == *player && encumbrance != derived.encumbrance {
+                bo_logging::Logger::new()
+                    .color(if encumbrance == Encumbrance::Unburdened { GREEN } else { ORANGE })
+                    .append(format!("You are now {}.", encumbrance.name().to_lowercase()))
+                    .log();
+            }
+            derived.encumbrance = encumbrance;
+
+            if let Some(stats) = combat_stats.get_mut(entity) {
+                // Extra hit points arrive fully healed
+                let max_hp = i32::max(1, derived.base_max_hp + attr.fitness.bonus * HP_PER_FITNESS);
+                if max_hp > stats.max_hp {
+                    stats.hp += max_hp - stats.max_hp;
+                }
+                stats.max_hp = max_hp;
+                stats.hp = i32::min(stats.hp, stats.max_hp);
+            }
+
+            if let Some(pool) = mana_pools.get_mut(entity) {
+                // As do extra points of mana
+                let max_mana = i32::max(0, pool.base_max + attr.intelligence.bonus * MANA_PER_INTELLIGENCE);
+                if max_mana > pool.max {
+                    pool.current += max_mana - pool.max;
+                }
+                pool.max = max_mana;
+                pool.current = i32::min(pool.current, pool.max);
+            }
+
+            if let Some(initiative) = initiatives.get_mut(entity) {
+                let (haste, slow) = status_effects.get(entity).map_or((0, 0), |statuses| {
+                    (statuses.magnitude(StatusKind::Haste), statuses.magnitude(StatusKind::Slow))
+                });
+
+                initiative.speed = i32::max(
+                    1,
+                    derived.base_speed + attr.quickness.bonus + haste
+                        - slow
+                        - derived.encumbrance.speed_penalty(),
+                );
+            }
+        }
+
+        stats_changed.clear();
+    }
+}
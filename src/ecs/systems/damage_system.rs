@@ -3,10 +3,21 @@ use super::*;
 pub struct DeleteDeadSystem {}
 
 impl<'a> System<'a> for DeleteDeadSystem {
-    type SystemData = (Entities<'a>, ReadStorage<'a, CombatStats>, ReadStorage<'a, Player>);
+    type SystemData =
+        (Entities<'a>, ReadStorage<'a, CombatStats>, ReadStorage<'a, Player>, WriteStorage<'a, Follower>);
 
     fn run(&mut self, data: Self::SystemData) {
-        let (entities, combat_stats, players) = data;
+        let (entities, combat_stats, players, mut followers) = data;
+
+        // Followers of a fallen leader are on their own now
+        let leaderless: Vec<Entity> = (&entities, &followers)
+            .join()
+            .filter(|(_, follower)| combat_stats.get(follower.leader).is_none_or(|stats| stats.hp < 1))
+            .map(|(entity, _)| entity)
+            .collect();
+        for entity in leaderless {
+            followers.remove(entity);
+        }
 
         #[cfg(not(target_arch = "wasm32"))]
         (&entities, &combat_stats, (&players).maybe())
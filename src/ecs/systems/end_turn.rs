@@ -3,16 +3,40 @@ use super::*;
 pub struct EndTurnSystem;
 
 impl<'a> System<'a> for EndTurnSystem {
-    type SystemData = (WriteExpect<'a, TurnState>, ReadExpect<'a, Entity>, ReadStorage<'a, CombatStats>);
+    #[allow(clippy::type_complexity)]
+    type SystemData = (
+        Entities<'a>,
+        WriteExpect<'a, TurnState>,
+        ReadExpect<'a, Entity>,
+        ReadStorage<'a, CombatStats>,
+        WriteStorage<'a, Initiative>,
+        WriteStorage<'a, MyTurn>,
+    );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (mut state, player, stats) = data;
+        let (entities, mut state, player, stats, mut initiatives, mut turns) = data;
 
         let current_state = *state;
         let mut next_state = match current_state {
-            TurnState::PreRun => TurnState::AwaitingInput,
-            TurnState::PlayerTurn => TurnState::MonsterTurn,
-            TurnState::MonsterTurn => TurnState::AwaitingInput,
+            TurnState::PreRun => TurnState::Ticking,
+            TurnState::PlayerTurn => {
+                spend_turn(*player, &mut initiatives, &mut turns);
+                TurnState::Ticking
+            }
+            TurnState::Ticking => {
+                // Everyone but the player has acted by now
+                let acted: Vec<Entity> =
+                    (&entities, &turns).join().map(|(entity, _)| entity).filter(|e| *e != *player).collect();
+                for entity in acted {
+                    spend_turn(entity, &mut initiatives, &mut turns);
+                }
+
+                if turns.contains(*player) {
+                    TurnState::AwaitingInput
+                } else {
+                    TurnState::Ticking
+                }
+            }
             _ => current_state,
         };
 
@@ -23,3 +47,11 @@ impl<'a> System<'a> for EndTurnSystem {
         *state = next_state
     }
 }
+
+fn spend_turn(entity: Entity, initiatives: &mut WriteStorage<Initiative>, turns: &mut WriteStorage<MyTurn>) {
+    if let Some(initiative) = initiatives.get_mut(entity) {
+        initiative.energy -= ACTION_COST;
+    }
+
+    turns.remove(entity);
+}
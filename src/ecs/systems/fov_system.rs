@@ -10,14 +10,23 @@ impl<'a> System<'a> for FovSystem {
         ReadStorage<'a, Player>,
         WriteStorage<'a, Hidden>,
         ReadStorage<'a, Name>,
+        ReadStorage<'a, StatusEffects>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (mut map, pos_storage, mut fov_storage, player_storage, mut hidden, names) = data;
+        let (mut map, pos_storage, mut fov_storage, player_storage, mut hidden, names, status_effects) = data;
+
+        for (fov, pos, player, statuses) in
+            (&mut fov_storage, &pos_storage, (&player_storage).maybe(), (&status_effects).maybe()).join()
+        {
+            let radius = if statuses.is_some_and(|statuses| statuses.has(StatusKind::Blindness)) {
+                i32::min(fov.radius, BLIND_VISION_RANGE)
+            } else {
+                fov.radius
+            };
 
-        for (fov, pos, player) in (&mut fov_storage, &pos_storage, (&player_storage).maybe()).join() {
             fov.is_dirty = false;
-            fov.visible_tiles = field_of_view_set(*pos, fov.radius, &*map);
+            fov.visible_tiles = field_of_view_set(*pos, radius, &*map);
 
             if player.is_some() {
                 map.clear_visible();
@@ -0,0 +1,147 @@
+use super::*;
+use std::collections::BTreeMap;
+
+/// Fire damage dealt each turn to whoever is standing in the flames
+const FIRE_DAMAGE: i32 = 4;
+/// Turns a fire burns on a tile it has spread to
+const FIRE_SPREAD_TURNS: i32 = 4;
+/// Percent chance each turn that a fire catches on each flammable neighbour
+const FIRE_SPREAD_CHANCE: i32 = 30;
+/// Poison damage per turn for breathing in gas
+const GAS_POISON_DAMAGE: i32 = 1;
+/// Turns the poison from a lungful of gas lasts
+const GAS_POISON_TURNS: i32 = 3;
+/// Percent chance each turn that a gas cloud drifts to a neighbouring tile
+const GAS_DRIFT_CHANCE: i32 = 50;
+
+/// Ticks every hazard on the map once per turn the player takes: hurts whoever is standing in it,
+/// spreads or drifts it, and clears away whatever has burned out or thinned away.
+pub struct HazardSystem {}
+
+impl<'a> System<'a> for HazardSystem {
+    type SystemData = (
+        ReadStorage<'a, MyTurn>,
+        ReadExpect<'a, Entity>, // The player
+        WriteExpect<'a, Map>,
+        WriteExpect<'a, EffectQueue>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (turns, player_entity, mut map, mut effects) = data;
+
+        // Hazards keep time with the player
+        if !turns.contains(*player_entity) {
+            return;
+        }
+
+        // Anything that spreads or drifts this turn is held back until every hazard already on the
+        // map has had its turn, so it can't hurt or spread again before the next
+        let hazards: Vec<(usize, Hazard)> = map.hazards.iter().map(|(idx, hazard)| (*idx, *hazard)).collect();
+        let mut arrivals: BTreeMap<usize, Hazard> = BTreeMap::new();
+
+        for (idx, hazard) in hazards {
+            match hazard.kind {
+                HazardKind::Fire => {
+                    effects.add_effect(
+                        None,
+                        EffectType::Damage(FIRE_DAMAGE, DamageType::Fire),
+                        Targets::Tile(idx),
+                    );
+                    spread_fire(&map, idx, &mut arrivals);
+                }
+                HazardKind::Gas => {
+                    effects.add_effect(
+                        None,
+                        EffectType::Status(StatusKind::Poison, GAS_POISON_TURNS, GAS_POISON_DAMAGE),
+                        Targets::Tile(idx),
+                    );
+
+                    if drift_gas(&mut map, idx, hazard, &mut arrivals) {
+                        continue;
+                    }
+                }
+            }
+
+            burn_down(&mut map, idx, hazard);
+        }
+
+        for (idx, hazard) in arrivals {
+            map.add_hazard(idx, hazard.kind, hazard.turns);
+        }
+    }
+}
+
+/// Holds back a hazard that has just arrived on a tile. Fire wins out over gas, as on the map.
+fn arrive(arrivals: &mut BTreeMap<usize, Hazard>, idx: usize, kind: HazardKind, turns: i32) {
+    let turns = match arrivals.get(&idx) {
+        Some(existing) if existing.kind == kind => i32::max(existing.turns, turns),
+        Some(existing) if existing.kind == HazardKind::Fire => return,
+        _ => turns,
+    };
+
+    arrivals.insert(idx, Hazard { kind, turns });
+}
+
+/// The tiles next to `idx` that are on the map
+fn neighbours(map: &Map, idx: usize) -> Vec<usize> {
+    let pt = map.index_to_point2d(idx);
+    [Point::new(-1, 0), Point::new(1, 0), Point::new(0, -1), Point::new(0, 1)]
+        .iter()
+        .map(|delta| pt + *delta)
+        .filter(|next| map.in_bounds(*next))
+        .map(|next| map.point2d_to_index(next))
+        .collect()
+}
+
+/// Fire catches on flammable ground and on any gas hanging next to it
+fn spread_fire(map: &Map, idx: usize, arrivals: &mut BTreeMap<usize, Hazard>) {
+    for next in neighbours(map, idx) {
+        let catches = match map.hazards.get(&next) {
+            Some(hazard) => hazard.kind == HazardKind::Gas,
+            None => map.is_flammable(next),
+        };
+
+        if catches && crate::rng::roll_dice(1, 100) <= FIRE_SPREAD_CHANCE {
+            arrive(arrivals, next, HazardKind::Fire, FIRE_SPREAD_TURNS);
+        }
+    }
+}
+
+/// Moves a gas cloud onto a clear neighbouring tile, thinning it as it goes. Returns whether it
+/// moved.
+fn drift_gas(map: &mut Map, idx: usize, hazard: Hazard, arrivals: &mut BTreeMap<usize, Hazard>) -> bool {
+    if crate::rng::roll_dice(1, 100) > GAS_DRIFT_CHANCE {
+        return false;
+    }
+
+    let open: Vec<usize> = neighbours(map, idx)
+        .into_iter()
+        .filter(|next| {
+            map.tiles[*next].walkable && !map.hazards.contains_key(next) && !arrivals.contains_key(next)
+        })
+        .collect();
+
+    if open.is_empty() {
+        return false;
+    }
+
+    let next = open[crate::rng::range(0, open.len() as i32) as usize];
+    map.hazards.remove(&idx);
+    arrive(arrivals, next, hazard.kind, hazard.turns - 1);
+    true
+}
+
+/// Counts a hazard down, clearing it once it's spent. Whatever burned away leaves bare floor.
+fn burn_down(map: &mut Map, idx: usize, hazard: Hazard) {
+    if hazard.turns > 1 {
+        if let Some(hazard) = map.hazards.get_mut(&idx) {
+            hazard.turns -= 1;
+        }
+        return;
+    }
+
+    map.hazards.remove(&idx);
+    if hazard.kind == HazardKind::Fire && map.is_flammable(idx) {
+        map.tiles[idx] = GameTile::floor();
+    }
+}
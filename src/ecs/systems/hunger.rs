@@ -7,53 +7,59 @@ impl<'a> System<'a> for HungerSystem {
     type SystemData = (
         Entities<'a>,
         WriteStorage<'a, HungerClock>,
+        ReadStorage<'a, MyTurn>,
+        ReadExpect<'a, HungerConfig>,
         ReadExpect<'a, Entity>, // The player
+        WriteExpect<'a, EffectQueue>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (entities, mut hunger_clock, player_entity) = data;
+        let (entities, mut hunger_clock, turns, config, player_entity, mut effects) = data;
 
-        for (entity, mut clock) in (&entities, &mut hunger_clock).join() {
-            clock.duration -= 1;
+        // Clocks only tick when their owner spends a turn
+        for (entity, mut clock, _turn) in (&entities, &mut hunger_clock, &turns).join() {
+            clock.nutrition = i32::max(0, clock.nutrition - config.consumption_at(clock.rate));
 
-            if clock.duration < 1 {
-                match clock.state {
-                    HungerState::WellFed => {
-                        clock.state = HungerState::Normal;
-                        clock.duration = 200;
-                        if entity == *player_entity {
+            let state = config.state_for(clock.nutrition);
+            if state != clock.state {
+                clock.state = state;
+
+                if entity == *player_entity {
+                    match state {
+                        HungerState::WellFed => {}
+                        HungerState::Normal => {
                             bo_logging::Logger::new()
                                 .color(ORANGE)
                                 .append("You are no longer well fed")
                                 .log();
                         }
-                    }
-                    HungerState::Normal => {
-                        clock.state = HungerState::Hungry;
-                        clock.duration = 200;
-                        if entity == *player_entity {
+                        HungerState::Hungry => {
                             bo_logging::Logger::new().color(ORANGE).append("You are hungry").log();
                         }
-                    }
-                    HungerState::Hungry => {
-                        clock.state = HungerState::Starving;
-                        clock.duration = 200;
-                        if entity == *player_entity {
+                        HungerState::Starving => {
                             bo_logging::Logger::new().color(RED).append("You are starving!").log();
                         }
                     }
-                    HungerState::Starving => {
-                        // Inflict damage from hunger
-                        if entity == *player_entity {
-                            bo_logging::Logger::new()
-                                .color(RED)
-                                .append("Your hunger pangs are getting painful! You suffer 1 hp damage.")
-                                .log();
-                        }
+                }
+            }
 
-                        add_effect(None, EffectType::Damage(1), Targets::Single(entity));
-                    }
+            if clock.state == HungerState::Starving {
+                // Inflict damage from hunger
+                if entity == *player_entity {
+                    bo_logging::Logger::new()
+                        .color(RED)
+                        .append(format!(
+                            "Your hunger pangs are getting painful! You suffer {} hp damage.",
+                            config.starvation_damage
+                        ))
+                        .log();
                 }
+
+                effects.add_effect(
+                    None,
+                    EffectType::Damage(config.starvation_damage, DamageType::Physical),
+                    Targets::Single(entity),
+                );
             }
         }
     }
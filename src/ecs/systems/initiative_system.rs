@@ -0,0 +1,45 @@
+use super::*;
+
+pub struct InitiativeSystem {}
+
+impl<'a> System<'a> for InitiativeSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, TurnState>,
+        WriteStorage<'a, Initiative>,
+        WriteStorage<'a, MyTurn>,
+        ReadStorage<'a, Point>,
+        ReadStorage<'a, CombatStats>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, turn_state, mut initiatives, mut turns, positions, combat_stats) = data;
+
+        if *turn_state != TurnState::Ticking {
+            return;
+        }
+
+        // Advance the clock just far enough for the next actor(s) to be ready. Everything here is
+        // integer math in entity order, so the schedule only ever depends on the seed.
+        let ticks = (&initiatives, &positions, &combat_stats)
+            .join()
+            .filter(|(_, _, stats)| stats.hp > 0)
+            .map(|(initiative, _, _)| initiative.ticks_until_ready())
+            .min()
+            .unwrap_or(0);
+
+        for (entity, initiative, _pos, stats) in
+            (&entities, &mut initiatives, &positions, &combat_stats).join()
+        {
+            if stats.hp < 1 {
+                continue;
+            }
+
+            initiative.energy += ticks * initiative.speed;
+            if initiative.energy >= ACTION_COST {
+                turns.insert(entity, MyTurn {}).expect("Unable to insert turn");
+            }
+        }
+    }
+}
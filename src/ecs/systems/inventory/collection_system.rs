@@ -4,27 +4,86 @@ pub struct ItemCollectionSystem {}
 
 impl<'a> System<'a> for ItemCollectionSystem {
     type SystemData = (
+        Entities<'a>,
         ReadExpect<'a, Entity>,
+        ReadExpect<'a, MasterDungeonMap>,
         WriteStorage<'a, WantsToPickupItem>,
         WriteStorage<'a, Point>,
         ReadStorage<'a, Name>,
         WriteStorage<'a, InBackpack>,
+        ReadStorage<'a, Item>,
+        ReadStorage<'a, DerivedStats>,
+        WriteStorage<'a, StatsChanged>,
+        WriteStorage<'a, Ammunition>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (player_entity, mut wants_pickup, mut positions, names, mut backpack) = data;
+        let (
+            entities,
+            player_entity,
+            dm,
+            mut wants_pickup,
+            mut positions,
+            names,
+            mut backpack,
+            items,
+            derived_stats,
+            mut stats_changed,
+            mut ammunition,
+        ) = data;
 
         for pickup in wants_pickup.join() {
+            // Refuse anything that would take the load past what can be carried at all
+            let weight =
+                items.get(pickup.item).map_or(0.0, |item| total_weight(item, ammunition.get(pickup.item)));
+            if let Some(derived) = derived_stats.get(pickup.collected_by) {
+                if derived.carried_weight + weight > derived.max_load() {
+                    if pickup.collected_by == *player_entity {
+                        let item_name = dm.display_name(&names.get(pickup.item).unwrap().0);
+                        bo_logging::Logger::new()
+                            .append("You can't carry the")
+                            .item_name(item_name)
+                            .append("as well.")
+                            .log();
+                    }
+                    continue;
+                }
+            }
+
             positions.remove(pickup.item);
 
-            backpack
-                .insert(pickup.item, InBackpack::new(pickup.collected_by))
-                .expect("Unable to insert backpack entry");
+            // Ammunition joins any bundle of the same kind that's already being carried
+            let bundle = ammunition.get(pickup.item).and_then(|picked| {
+                (&entities, &backpack, &names, &ammunition)
+                    .join()
+                    .find(|(_, pack, name, ammo)| {
+                        pack.owner == pickup.collected_by
+                            && ammo.kind == picked.kind
+                            && names.get(pickup.item).is_some_and(|picked_name| picked_name.0 == name.0)
+                    })
+                    .map(|(bundle, ..)| (bundle, picked.count))
+            });
+
+            match bundle {
+                Some((bundle, count)) => {
+                    if let Some(ammo) = ammunition.get_mut(bundle) {
+                        ammo.count += count;
+                    }
+                    entities.delete(pickup.item).expect("Delete Failed");
+                }
+                None => {
+                    backpack
+                        .insert(pickup.item, InBackpack::new(pickup.collected_by))
+                        .expect("Unable to insert backpack entry");
+                }
+            }
 
             if pickup.collected_by == *player_entity {
-                let item_name = names.get(pickup.item).unwrap().0.clone();
+                let item_name = dm.display_name(&names.get(pickup.item).unwrap().0);
                 bo_logging::Logger::new().append("You pick up the").item_name(item_name).log();
             }
+
+            stats_changed.insert(pickup.collected_by, StatsChanged {}).expect("Unable to insert marker");
         }
 
         wants_pickup.clear();
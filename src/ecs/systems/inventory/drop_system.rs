@@ -5,26 +5,59 @@ pub struct ItemDropSystem {}
 impl<'a> System<'a> for ItemDropSystem {
     type SystemData = (
         ReadExpect<'a, Entity>,
+        ReadExpect<'a, MasterDungeonMap>,
         Entities<'a>,
         WriteStorage<'a, WantsToDropItem>,
         ReadStorage<'a, Name>,
         WriteStorage<'a, Point>,
         WriteStorage<'a, InBackpack>,
+        WriteStorage<'a, Equipped>,
+        WriteStorage<'a, Cursed>,
+        WriteStorage<'a, StatsChanged>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (player_entity, entities, mut wants_drop, names, mut positions, mut backpack) = data;
+        let (
+            player_entity,
+            dm,
+            entities,
+            mut wants_drop,
+            names,
+            mut positions,
+            mut backpack,
+            mut equipped,
+            mut cursed,
+            mut stats_changed,
+        ) = data;
 
         for (entity, to_drop) in (&entities, &wants_drop).join() {
+            // A cursed item won't come off to be dropped
+            if equipped.contains(to_drop.item) {
+                if let Some(curse) = cursed.get_mut(to_drop.item) {
+                    curse.known = true;
+                    if entity == *player_entity {
+                        bo_logging::Logger::new()
+                            .append("You can't remove the")
+                            .item_name(dm.display_name(&names.get(to_drop.item).unwrap().0))
+                            .append("- it's cursed!")
+                            .log();
+                    }
+                    continue;
+                }
+            }
+
             let dropped_pos = positions.get(entity).unwrap();
 
             positions.insert(to_drop.item, *dropped_pos).expect("Unable to insert position");
             backpack.remove(to_drop.item);
+            equipped.remove(to_drop.item);
 
             if entity == *player_entity {
-                let item_name = names.get(to_drop.item).unwrap().0.clone();
+                let item_name = dm.display_name(&names.get(to_drop.item).unwrap().0);
                 bo_logging::Logger::new().append("You drop the").item_name(item_name).log();
             }
+
+            stats_changed.insert(entity, StatsChanged {}).expect("Unable to insert marker");
         }
 
         wants_drop.clear();
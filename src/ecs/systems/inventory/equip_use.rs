@@ -3,18 +3,35 @@ use super::*;
 pub struct ItemEquipOnUse {}
 
 impl<'a> System<'a> for ItemEquipOnUse {
+    #[allow(clippy::type_complexity)]
     type SystemData = (
         Entities<'a>,
         ReadExpect<'a, Entity>,
+        ReadExpect<'a, MasterDungeonMap>,
         WriteStorage<'a, WantsToUseItem>,
         ReadStorage<'a, Name>,
         ReadStorage<'a, Equippable>,
+        ReadStorage<'a, TwoHanded>,
+        WriteStorage<'a, Cursed>,
         WriteStorage<'a, Equipped>,
         WriteStorage<'a, InBackpack>,
+        WriteStorage<'a, StatsChanged>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (entities, player, mut wants_use, names, equippable, mut equipped, mut backpack) = data;
+        let (
+            entities,
+            player,
+            dm,
+            mut wants_use,
+            names,
+            equippable,
+            two_handed,
+            mut cursed,
+            mut equipped,
+            mut backpack,
+            mut stats_changed,
+        ) = data;
 
         let mut remove_use: Vec<Entity> = Vec::new();
         for (target, useitem, can_equip) in (&entities, &wants_use)
@@ -22,15 +39,63 @@ impl<'a> System<'a> for ItemEquipOnUse {
             .filter(|(_, useitem)| equippable.get(useitem.item).is_some())
             .map(|(e, useitem)| (e, useitem, equippable.get(useitem.item).unwrap()))
         {
-            let target_slot = can_equip.slot;
+            let slot_taken = |slot: EquipmentSlot| {
+                (&equipped).join().any(|equip| equip.owner == target && equip.slot == slot)
+            };
+
+            // A second ring goes on the other hand, if it's free
+            let mut target_slot = can_equip.slot;
+            if let Some(alternative) = target_slot.alternative() {
+                if slot_taken(target_slot) && !slot_taken(alternative) {
+                    target_slot = alternative;
+                }
+            }
+
+            // Two-handed weapons and shields can't be held at the same time
+            let is_two_handed = two_handed.get(useitem.item).is_some();
+            let clashes = |item: Entity, slot: EquipmentSlot| {
+                slot == target_slot
+                    || (is_two_handed && slot == EquipmentSlot::Shield)
+                    || (target_slot == EquipmentSlot::Shield && two_handed.get(item).is_some())
+            };
+
+            // Nothing gets swapped out while a cursed item is in the way
+            let stuck = (&entities, &equipped)
+                .join()
+                .find(|(item_entity, already_equipped)| {
+                    already_equipped.owner == target
+                        && clashes(*item_entity, already_equipped.slot)
+                        && cursed.contains(*item_entity)
+                })
+                .map(|(item_entity, _)| item_entity);
+
+            if let Some(stuck) = stuck {
+                if let Some(curse) = cursed.get_mut(stuck) {
+                    curse.known = true;
+                }
+
+                if target == *player {
+                    bo_logging::Logger::new()
+                        .append("You can't remove the")
+                        .item_name(dm.display_name(&names.get(stuck).unwrap().0))
+                        .append("- it's cursed!")
+                        .log();
+                }
+
+                remove_use.push(target);
+                continue;
+            }
 
             // Remove any items the target has in the item's slot
             let mut to_unequip: Vec<Entity> = Vec::new();
             for (item_entity, already_equipped, name) in (&entities, &equipped, &names).join() {
-                if already_equipped.owner == target && already_equipped.slot == target_slot {
+                if already_equipped.owner == target && clashes(item_entity, already_equipped.slot) {
                     to_unequip.push(item_entity);
                     if target == *player {
-                        bo_logging::Logger::new().append("You unequip").item_name(&name.0).log();
+                        bo_logging::Logger::new()
+                            .append("You unequip")
+                            .item_name(dm.display_name(&name.0))
+                            .log();
                     }
                 }
             }
@@ -47,12 +112,18 @@ impl<'a> System<'a> for ItemEquipOnUse {
                 .expect("Unable to insert equipped component");
 
             if target == *player {
-                bo_logging::Logger::new()
-                    .append("You equip")
-                    .item_name(&names.get(useitem.item).unwrap().0)
-                    .log();
+                let item_name = dm.display_name(&names.get(useitem.item).unwrap().0);
+                bo_logging::Logger::new().append("You equip").item_name(&item_name).log();
+
+                // The curse makes itself known the moment it takes hold
+                if let Some(curse) = cursed.get_mut(useitem.item).filter(|curse| !curse.known) {
+                    curse.known = true;
+                    bo_logging::Logger::new().append("The").item_name(&item_name).append("is cursed!").log();
+                }
             }
 
+            stats_changed.insert(target, StatsChanged {}).expect("Unable to insert marker");
+
             // Done with item
             remove_use.push(target);
         }
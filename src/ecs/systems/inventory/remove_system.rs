@@ -6,17 +6,45 @@ impl<'a> System<'a> for ItemRemoveSystem {
     #[allow(clippy::type_complexity)]
     type SystemData = (
         Entities<'a>,
+        ReadExpect<'a, Entity>,
+        ReadExpect<'a, MasterDungeonMap>,
         WriteStorage<'a, WantsToRemoveItem>,
+        ReadStorage<'a, Name>,
+        WriteStorage<'a, Cursed>,
         WriteStorage<'a, Equipped>,
         WriteStorage<'a, InBackpack>,
+        WriteStorage<'a, StatsChanged>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (entities, mut wants_remove, mut equipped, mut backpack) = data;
+        let (
+            entities,
+            player_entity,
+            dm,
+            mut wants_remove,
+            names,
+            mut cursed,
+            mut equipped,
+            mut backpack,
+            mut stats_changed,
+        ) = data;
 
         for (entity, to_remove) in (&entities, &wants_remove).join() {
+            if let Some(curse) = cursed.get_mut(to_remove.item) {
+                curse.known = true;
+                if entity == *player_entity {
+                    bo_logging::Logger::new()
+                        .append("You can't remove the")
+                        .item_name(dm.display_name(&names.get(to_remove.item).unwrap().0))
+                        .append("- it's cursed!")
+                        .log();
+                }
+                continue;
+            }
+
             equipped.remove(to_remove.item);
             backpack.insert(to_remove.item, InBackpack::new(entity)).expect("Unable to insert backpack");
+            stats_changed.insert(entity, StatsChanged {}).expect("Unable to insert marker");
         }
 
         wants_remove.clear();
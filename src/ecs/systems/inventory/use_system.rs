@@ -8,24 +8,71 @@ impl<'a> System<'a> for ItemUseSystem {
         Entities<'a>,
         ReadExpect<'a, Entity>,
         WriteExpect<'a, Map>,
+        WriteExpect<'a, MasterDungeonMap>,
         WriteStorage<'a, WantsToUseItem>,
         ReadStorage<'a, AreaOfEffect>,
         ReadStorage<'a, Equippable>,
+        ReadStorage<'a, Charges>,
+        ReadStorage<'a, Name>,
+        ReadStorage<'a, Point>,
+        WriteExpect<'a, EffectQueue>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (entities, player_entity, map, mut wants_use, aoe, equippable) = data;
+        let (
+            entities,
+            player_entity,
+            map,
+            mut dm,
+            mut wants_use,
+            aoe,
+            equippable,
+            charges,
+            names,
+            positions,
+            mut effects,
+        ) = data;
+
+        for (entity, useitem) in (&entities, &wants_use).join() {
+            if equippable.contains(useitem.item) {
+                continue;
+            }
+
+            // A spent item does nothing until it's recharged
+            if charges.get(useitem.item).is_some_and(|charges| charges.remaining < 1) {
+                if let (true, Some(item_name)) = (entity == *player_entity, names.get(useitem.item)) {
+                    bo_logging::Logger::new()
+                        .append("The")
+                        .item_name(dm.display_name(&item_name.0))
+                        .append("has no charges left.")
+                        .log();
+                }
+                continue;
+            }
+
+            if entity == *player_entity {
+                // Using an item is the surest way to find out what it is
+                if let Some(item_name) = names.get(useitem.item) {
+                    dm.identify(&item_name.0);
+                }
+            } else if let (Some(name), Some(item_name)) = (names.get(entity), names.get(useitem.item)) {
+                bo_logging::Logger::new()
+                    .npc_name(&name.0)
+                    .append("uses")
+                    .item_name(dm.display_name(&item_name.0))
+                    .log();
+            }
 
-        for (entity, useitem, ()) in (&entities, &wants_use, !&equippable).join() {
             // Call the effects system
-            add_effect(
+            effects.add_effect(
                 Some(entity),
                 EffectType::ItemUse(useitem.item),
                 match useitem.target {
-                    None => Targets::Single(*player_entity),
+                    None => Targets::Single(entity),
                     Some(target) => {
                         if let Some(aoe) = aoe.get(useitem.item) {
-                            Targets::Tiles(aoe_tiles(&map, target, aoe.radius))
+                            let origin = positions.get(entity).copied().unwrap_or(target);
+                            Targets::Tiles(aoe_tiles(&map, origin, target, aoe))
                         } else {
                             Targets::Tile(map.point2d_to_index(target))
                         }
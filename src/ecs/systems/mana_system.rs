@@ -0,0 +1,32 @@
+use super::*;
+
+/// Turns it takes an average mind to recover a point of mana
+const MANA_REGEN_TURNS: i32 = 6;
+/// No mind recovers mana faster than a point every this many turns
+const MIN_MANA_REGEN_TURNS: i32 = 2;
+
+pub struct ManaRegenSystem {}
+
+impl<'a> System<'a> for ManaRegenSystem {
+    type SystemData = (WriteStorage<'a, ManaPool>, ReadStorage<'a, Attributes>, ReadStorage<'a, MyTurn>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (mut mana_pools, attributes, turns) = data;
+
+        // Mana only comes back as its owner spends turns
+        for (pool, attr, _turn) in (&mut mana_pools, (&attributes).maybe(), &turns).join() {
+            if pool.current >= pool.max {
+                pool.regen_timer = 0;
+                continue;
+            }
+
+            pool.regen_timer += 1;
+
+            let intelligence = attr.map_or(0, |attr| attr.intelligence.bonus);
+            if pool.regen_timer >= i32::max(MIN_MANA_REGEN_TURNS, MANA_REGEN_TURNS - intelligence) {
+                pool.regen_timer = 0;
+                pool.current += 1;
+            }
+        }
+    }
+}
@@ -1,8 +1,34 @@
 use super::*;
 
+/// Every target is at least this hard to hit, before defense and armour are added
+const BASE_DEFENCE: i32 = 10;
+/// A natural roll of this always hits, and rolls damage twice
+const CRITICAL_HIT: i32 = 20;
+/// A natural roll of this always misses
+const FUMBLE: i32 = 1;
+const UNARMED_DAMAGE: Dice = Dice { n_dice: 1, die_type: 4, bonus: 0 };
+
+/// Totals up a bonus across everything `owner` has equipped, whichever slots it's worn in
+fn equipped_bonus<T: Component>(
+    bonuses: &ReadStorage<T>,
+    equipped: &ReadStorage<Equipped>,
+    owner: Entity,
+    amount: impl Fn(&T) -> i32,
+) -> i32 {
+    (bonuses, equipped)
+        .join()
+        .filter(|(_, equipped_by)| equipped_by.owner == owner)
+        .map(|(b, _)| amount(b))
+        .sum()
+}
+
+/// Attacks roll `1d20 + power + weapon bonuses + quickness` against `BASE_DEFENCE + defense + armour`.
+/// Hits deal the weapon's damage dice plus power, weapon bonuses and might, and always do at least 1
+/// damage.
 pub struct MeleeCombatSystem {}
 
 impl<'a> System<'a> for MeleeCombatSystem {
+    #[allow(clippy::type_complexity)]
     type SystemData = (
         Entities<'a>,
         WriteStorage<'a, WantsToMelee>,
@@ -10,8 +36,13 @@ impl<'a> System<'a> for MeleeCombatSystem {
         ReadStorage<'a, CombatStats>,
         ReadStorage<'a, MeleePowerBonus>,
         ReadStorage<'a, DefenseBonus>,
+        ReadStorage<'a, MeleeDamage>,
+        ReadStorage<'a, DerivedStats>,
         ReadStorage<'a, Equipped>,
         ReadStorage<'a, HungerClock>,
+        ReadStorage<'a, AttackCost>,
+        WriteStorage<'a, Initiative>,
+        WriteExpect<'a, EffectQueue>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
@@ -22,20 +53,30 @@ impl<'a> System<'a> for MeleeCombatSystem {
             combat_stats,
             melee_power_bonuses,
             defense_bonuses,
+            melee_damage,
+            derived_stats,
             equipped,
             hunger_clock,
+            attack_costs,
+            mut initiatives,
+            mut effects,
         ) = data;
 
         for (entity, wants_melee, name, stats) in (&entities, &wants_melee, &names, &combat_stats).join() {
             if stats.hp > 0 {
-                let mut offensive_bonus = 0;
-                for (_item_entity, power_bonus, equipped_by) in
-                    (&entities, &melee_power_bonuses, &equipped).join()
-                {
-                    if equipped_by.owner == entity {
-                        offensive_bonus += power_bonus.power;
-                    }
-                }
+                let mut offensive_bonus =
+                    equipped_bonus(&melee_power_bonuses, &equipped, entity, |bonus| bonus.power);
+
+                // Entities without attributes fight on their raw stats alone
+                let derived = derived_stats.get(entity).cloned().unwrap_or_else(|| DerivedStats::new(0, 0));
+
+                // Wielded weapon first, then whatever nature gave us, then bare fists
+                let weapon_damage = (&melee_damage, &equipped)
+                    .join()
+                    .find(|(_, equipped_by)| equipped_by.owner == entity)
+                    .map(|(damage, _)| damage.clone())
+                    .or_else(|| melee_damage.get(entity).cloned())
+                    .unwrap_or_else(|| MeleeDamage::new(UNARMED_DAMAGE, DamageType::Physical));
 
                 // Hunger Bonus
                 let hc = hunger_clock.get(entity);
@@ -45,48 +86,86 @@ impl<'a> System<'a> for MeleeCombatSystem {
                     }
                 }
 
-                let target_stats = combat_stats.get(wants_melee.target).unwrap();
+                // The target may not be fightable (or may already be gone)
+                let (target_stats, target_name) =
+                    match (combat_stats.get(wants_melee.target), names.get(wants_melee.target)) {
+                        (Some(target_stats), Some(target_name)) => (target_stats, target_name),
+                        _ => continue,
+                    };
+
                 if target_stats.hp > 0 {
-                    let mut defensive_bonus = 0;
-                    for (_item_entity, defense_bonus, equipped_by) in
-                        (&entities, &defense_bonuses, &equipped).join()
-                    {
-                        if equipped_by.owner == wants_melee.target {
-                            defensive_bonus += defense_bonus.defense;
-                        }
+                    // Heavy (or light) weapons take more (or less) time to swing
+                    if let Some(initiative) = initiatives.get_mut(entity) {
+                        initiative.energy -=
+                            equipped_bonus(&attack_costs, &equipped, entity, |cost| cost.0 - ACTION_COST);
                     }
 
-                    let target_name = names.get(wants_melee.target).unwrap();
-                    let damage = i32::max(
-                        0,
-                        (stats.power + offensive_bonus) - (target_stats.defense + defensive_bonus),
-                    );
+                    let defensive_bonus =
+                        equipped_bonus(&defense_bonuses, &equipped, wants_melee.target, |bonus| {
+                            bonus.defense
+                        });
 
-                    if damage == 0 {
-                        // Miss
+                    let defence = BASE_DEFENCE + target_stats.defense + defensive_bonus;
+                    let natural_roll = crate::rng::roll_dice(1, 20);
+                    let attack_roll = natural_roll + stats.power + offensive_bonus + derived.to_hit;
+                    let roll_text = format!("({} vs {})", attack_roll, defence);
+
+                    if natural_roll == FUMBLE {
                         bo_logging::Logger::new()
                             .color(CYAN)
                             .append(&name.0)
                             .color(WHITE)
-                            .append("attacks")
+                            .append("fumbles the attack on")
                             .color(CYAN)
                             .append(&target_name.0)
                             .color(WHITE)
-                            .append("but can't connect.")
+                            .append("!")
                             .log();
 
-                        add_hit_miss_particle(wants_melee.target);
-                    } else {
+                        effects.add_hit_miss_particle(wants_melee.target);
+                    } else if natural_roll == CRITICAL_HIT || attack_roll >= defence {
+                        // Criticals roll the weapon's dice twice
+                        let mut damage =
+                            weapon_damage.dice.roll() + stats.power + offensive_bonus + derived.melee_damage;
+                        if natural_roll == CRITICAL_HIT {
+                            damage += weapon_damage.dice.roll();
+                        }
+                        let damage = i32::max(1, damage);
+
                         bo_logging::Logger::new()
                             .npc_name(&name.0)
-                            .append("hits")
+                            .append(if natural_roll == CRITICAL_HIT { "critically hits" } else { "hits" })
                             .npc_name(&target_name.0)
                             .append("for")
                             .damage(damage)
-                            .append("hp.")
+                            .color(weapon_damage.damage_type.color())
+                            .append(weapon_damage.damage_type.name())
+                            .color(WHITE)
+                            .append("damage.")
+                            .append(roll_text)
+                            .log();
+
+                        effects.add_single_damage_effect(
+                            Some(entity),
+                            wants_melee.target,
+                            damage,
+                            weapon_damage.damage_type,
+                        );
+                    } else {
+                        // Miss
+                        bo_logging::Logger::new()
+                            .color(CYAN)
+                            .append(&name.0)
+                            .color(WHITE)
+                            .append("attacks")
+                            .color(CYAN)
+                            .append(&target_name.0)
+                            .color(WHITE)
+                            .append("but misses.")
+                            .append(roll_text)
                             .log();
 
-                        add_single_damage_effect(Some(entity), wants_melee.target, damage);
+                        effects.add_hit_miss_particle(wants_melee.target);
                     }
                 }
             }
@@ -2,27 +2,41 @@ use crate::construct_dispatcher;
 use crate::prelude::*;
 
 mod ai;
+mod attribute_system;
 mod damage_system;
 mod end_turn;
 mod fov_system;
+mod hazard_system;
 mod hunger;
+mod initiative_system;
 mod inventory;
+mod mana_system;
 mod map_indexing_system;
 mod melee_combat_system;
 mod particle_system;
+mod ranged_combat_system;
 mod render;
+mod spell_system;
+mod status_system;
 mod trigger_system;
 
 pub use ai::*;
+pub use attribute_system::AttributeSystem;
 pub use damage_system::DeleteDeadSystem;
 pub use end_turn::EndTurnSystem;
 pub use fov_system::FovSystem;
+pub use hazard_system::HazardSystem;
 pub use hunger::HungerSystem;
+pub use initiative_system::InitiativeSystem;
 pub use inventory::*;
+pub use mana_system::ManaRegenSystem;
 pub use map_indexing_system::MapIndexingSystem;
 pub use melee_combat_system::MeleeCombatSystem;
 pub use particle_system::{ParticleSpawnSystem, ParticleUpdateSystem};
+pub use ranged_combat_system::{line_of_fire, RangedCombatSystem};
 pub use render::*;
+pub use spell_system::SpellCastSystem;
+pub use status_system::StatusEffectSystem;
 pub use trigger_system::TriggerSystem;
 
 pub fn new_dispatcher() -> Box<dyn UnifiedDispatcher + 'static> {
@@ -31,12 +45,17 @@ pub fn new_dispatcher() -> Box<dyn UnifiedDispatcher + 'static> {
         // (FovSystem, "fov", &[]),
         (TriggerSystem, "triggers", &[]),
         (MeleeCombatSystem, "melee_combat", &[]),
+        (RangedCombatSystem, "ranged_combat", &[]),
         (ItemCollectionSystem, "pickup", &[]),
         (ItemEquipOnUse, "equip", &[]),
         (ItemUseSystem, "use", &[]),
+        (SpellCastSystem, "cast", &[]),
         (ItemDropSystem, "drop", &[]),
         (ItemRemoveSystem, "remove", &[]),
-        (HungerSystem, "hunger", &[])
+        (AttributeSystem, "attributes", &["equip", "remove"]),
+        (HungerSystem, "hunger", &[]),
+        (HazardSystem, "hazards", &[]),
+        (ManaRegenSystem, "mana_regen", &[])
     );
 
     new_dispatch()
@@ -45,9 +64,11 @@ pub fn new_dispatcher() -> Box<dyn UnifiedDispatcher + 'static> {
 pub fn new_ticking() -> Box<dyn UnifiedDispatcher + 'static> {
     construct_dispatcher!(
         (FovSystem, "fov", &[]),
-        (EndTurnSystem, "end_turn", &[]),
-        (MonsterAISystem, "monster_ai", &[]),
-        (BystanderAI, "bystander_ai", &[]),
+        (InitiativeSystem, "initiative", &[]),
+        (StatusEffectSystem, "status_effects", &["initiative"]),
+        (MonsterAISystem, "monster_ai", &["status_effects"]),
+        (BystanderAI, "bystander_ai", &["status_effects"]),
+        (EndTurnSystem, "end_turn", &["monster_ai", "bystander_ai"]),
         (ParticleSpawnSystem, "particle_spawn", &[]),
         (ParticleUpdateSystem, "particle_update", &[]),
         (DeleteDeadSystem, "delete_dead", &[])
@@ -18,7 +18,10 @@ impl<'a> System<'a> for ParticleSpawnSystem {
                 .build_entity()
                 .with(new_particle.pt, &mut positions)
                 .with(Glyph::new(new_particle.glyph, new_particle.color, RenderOrder::Particle), &mut glyphs)
-                .with(ParticleLifetime::new(new_particle.lifetime, None), &mut particles)
+                .with(
+                    ParticleLifetime::new(new_particle.lifetime, new_particle.animation.clone()),
+                    &mut particles,
+                )
                 .build();
         }
 
@@ -0,0 +1,260 @@
+use super::*;
+
+/// How long (in ms) a shot lingers on each tile of its flight
+const SHOT_STEP_TIME: f32 = 40.0;
+
+/// Traces a shot from `start` towards `end`, returning each tile it passes through. The shot
+/// stops short of the first wall and stops on the first tile-blocking entity in its way.
+pub fn line_of_fire(map: &Map, start: Point, end: Point) -> Vec<Point> {
+    let mut path = Vec::new();
+
+    for pt in line2d_bresenham(start, end).into_iter().filter(|pt| *pt != start) {
+        if !map.in_bounds(pt) {
+            break;
+        }
+
+        let idx = map.point2d_to_index(pt);
+        if map.tiles[idx].opaque {
+            break;
+        }
+
+        path.push(pt);
+        if crate::spatial::get_blocking_entity(idx).is_some() {
+            break;
+        }
+    }
+
+    path
+}
+
+/// What actually flies through the air, and so what might be left lying where it lands
+enum Projectile {
+    /// A mob's own attack, or a weapon that needs no ammunition
+    Nothing,
+    /// One shot from a bundle of ammunition
+    Ammo(Entity),
+    /// The weapon itself
+    Thrown(Entity),
+}
+
+struct Shot {
+    damage: i32,
+    damage_type: DamageType,
+    glyph: FontCharType,
+    projectile: Projectile,
+}
+
+pub struct RangedCombatSystem {}
+
+impl<'a> System<'a> for RangedCombatSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        ReadExpect<'a, Map>,
+        ReadExpect<'a, MasterDungeonMap>,
+        Read<'a, LazyUpdate>,
+        WriteExpect<'a, ParticleBuilder>,
+        WriteStorage<'a, WantsToShoot>,
+        WriteStorage<'a, RangedAttack>,
+        ReadStorage<'a, RangedWeapon>,
+        WriteStorage<'a, Ammunition>,
+        WriteStorage<'a, Equipped>,
+        WriteStorage<'a, Cursed>,
+        ReadStorage<'a, InBackpack>,
+        ReadStorage<'a, Item>,
+        ReadStorage<'a, Glyph>,
+        ReadStorage<'a, Name>,
+        WriteStorage<'a, Point>,
+        ReadStorage<'a, CombatStats>,
+        WriteStorage<'a, StatsChanged>,
+        WriteExpect<'a, EffectQueue>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            player_entity,
+            map,
+            dm,
+            lazy,
+            mut particle_builder,
+            mut wants_shoot,
+            mut ranged_attacks,
+            ranged_weapons,
+            mut ammunition,
+            mut equipped,
+            mut cursed,
+            backpack,
+            items,
+            glyphs,
+            names,
+            mut positions,
+            combat_stats,
+            mut stats_changed,
+            mut effects,
+        ) = data;
+
+        let mut landings: Vec<(Entity, Projectile, Point)> = Vec::new();
+
+        for (entity, wants_shoot, name, pos, stats) in
+            (&entities, &wants_shoot, &names, &positions, &combat_stats).join()
+        {
+            if stats.hp < 1 {
+                continue;
+            }
+
+            let target_pos = match positions.get(wants_shoot.target) {
+                Some(target_pos) => *target_pos,
+                None => continue,
+            };
+
+            // A mob's own attack first, then whatever ranged weapon the shooter has equipped
+            let shot = if let Some(attack) = ranged_attacks.get_mut(entity) {
+                if !attack.is_ready() {
+                    continue;
+                }
+
+                attack.cooldown_remaining = attack.cooldown;
+                if let Some(ammo) = &mut attack.ammo {
+                    *ammo -= 1;
+                }
+
+                Shot {
+                    damage: attack.damage,
+                    damage_type: attack.damage_type,
+                    glyph: attack.glyph,
+                    projectile: Projectile::Nothing,
+                }
+            } else if let Some((weapon_entity, weapon, _)) =
+                (&entities, &ranged_weapons, &equipped).join().find(|(_, _, equip)| equip.owner == entity)
+            {
+                let projectile = if weapon.thrown {
+                    // A cursed weapon won't leave the hand, any more than it can be taken off
+                    if let Some(curse) = cursed.get_mut(weapon_entity) {
+                        curse.known = true;
+                        if entity == *player_entity {
+                            bo_logging::Logger::new()
+                                .append("You can't throw the")
+                                .item_name(dm.display_name(&names.get(weapon_entity).unwrap().0))
+                                .append("- it's cursed!")
+                                .log();
+                        }
+                        continue;
+                    }
+
+                    Projectile::Thrown(weapon_entity)
+                } else if let Some(kind) = &weapon.ammo {
+                    match (&entities, &ammunition, &backpack)
+                        .join()
+                        .find(|(_, ammo, pack)| pack.owner == entity && ammo.kind == *kind && ammo.count > 0)
+                    {
+                        Some((bundle, ..)) => Projectile::Ammo(bundle),
+                        None => {
+                            bo_logging::Logger::new().npc_name(&name.0).append("is out of ammunition.").log();
+                            continue;
+                        }
+                    }
+                } else {
+                    Projectile::Nothing
+                };
+
+                let glyph = match projectile {
+                    Projectile::Ammo(bundle) => glyphs.get(bundle),
+                    _ => glyphs.get(weapon_entity),
+                };
+
+                Shot {
+                    damage: i32::max(1, weapon.damage.roll()),
+                    damage_type: weapon.damage_type,
+                    glyph: glyph.map_or(to_cp437('*'), |glyph| glyph.glyph),
+                    projectile,
+                }
+            } else {
+                continue;
+            };
+
+            let path = line_of_fire(&map, *pos, target_pos);
+
+            // Whoever is standing at the end of the flight takes the hit
+            let victim = path
+                .last()
+                .and_then(|pt| crate::spatial::get_blocking_entity(map.point2d_to_index(*pt)))
+                .filter(|victim| combat_stats.get(*victim).is_some_and(|stats| stats.hp > 0));
+
+            let landing = path.last().copied().unwrap_or(*pos);
+            let mut flight = vec![*pos];
+            flight.extend(path);
+            particle_builder.request_path(flight, ColorPair::new(YELLOW, BLACK), shot.glyph, SHOT_STEP_TIME);
+
+            match victim.and_then(|victim| names.get(victim).map(|victim_name| (victim, victim_name))) {
+                Some((victim, victim_name)) => {
+                    bo_logging::Logger::new()
+                        .npc_name(&name.0)
+                        .append("shoots")
+                        .npc_name(&victim_name.0)
+                        .append("for")
+                        .damage(shot.damage)
+                        .color(shot.damage_type.color())
+                        .append(shot.damage_type.name())
+                        .color(WHITE)
+                        .append("damage.")
+                        .log();
+
+                    effects.add_single_damage_effect(Some(entity), victim, shot.damage, shot.damage_type);
+                }
+                None => {
+                    bo_logging::Logger::new()
+                        .npc_name(&name.0)
+                        .append("shoots, but the shot goes wide.")
+                        .log();
+                }
+            }
+
+            landings.push((entity, shot.projectile, landing));
+        }
+
+        // Whatever was fired comes down where the shot ended
+        for (shooter, projectile, landing) in landings {
+            match projectile {
+                Projectile::Nothing => continue,
+                Projectile::Thrown(weapon) => {
+                    equipped.remove(weapon);
+                    positions.insert(weapon, landing).expect("Unable to insert position");
+                }
+                Projectile::Ammo(bundle) => {
+                    let recover_chance = match ammunition.get_mut(bundle) {
+                        Some(ammo) => {
+                            ammo.count -= 1;
+                            ammo.recover_chance
+                        }
+                        None => continue,
+                    };
+
+                    if crate::rng::roll_dice(1, 100) <= recover_chance {
+                        if let (Some(item), Some(name), Some(glyph), Some(ammo)) =
+                            (items.get(bundle), names.get(bundle), glyphs.get(bundle), ammunition.get(bundle))
+                        {
+                            lazy.create_entity(&entities)
+                                .with(landing)
+                                .with(item.clone())
+                                .with(Name(name.0.clone()))
+                                .with(*glyph)
+                                .with(Ammunition { count: 1, ..ammo.clone() })
+                                .marked::<SimpleMarker<SerializeMe>>()
+                                .build();
+                        }
+                    }
+
+                    if ammunition.get(bundle).is_some_and(|ammo| ammo.count < 1) {
+                        entities.delete(bundle).expect("Delete Failed");
+                    }
+                }
+            }
+
+            stats_changed.insert(shooter, StatsChanged {}).expect("Unable to insert marker");
+        }
+
+        wants_shoot.clear();
+    }
+}
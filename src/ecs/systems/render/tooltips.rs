@@ -36,13 +36,14 @@ impl<'a> System<'a> for RenderTooltips {
         ReadExpect<'a, Map>,
         ReadExpect<'a, CameraView>,
         ReadExpect<'a, (i32, i32)>,
+        ReadExpect<'a, MasterDungeonMap>,
         ReadStorage<'a, Hidden>,
         ReadStorage<'a, CombatStats>,
         ReadStorage<'a, Name>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (map, camera, mouse_pos, hidden, stats, names) = data;
+        let (map, camera, mouse_pos, dm, hidden, stats, names) = data;
 
         let mut draw_batch = DrawBatch::new();
 
@@ -66,7 +67,7 @@ impl<'a> System<'a> for RenderTooltips {
             let mut tip = Tooltip::new();
 
             let item_name = names.get(entity).unwrap();
-            tip.add(CYAN, item_name.0.to_string());
+            tip.add(CYAN, dm.display_name(&item_name.0));
 
             // Comment on pools
             let stat = stats.get(entity);
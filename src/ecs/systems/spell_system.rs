@@ -0,0 +1,75 @@
+use super::*;
+
+pub struct SpellCastSystem {}
+
+impl<'a> System<'a> for SpellCastSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        ReadExpect<'a, Map>,
+        WriteStorage<'a, WantsToCastSpell>,
+        ReadStorage<'a, Spell>,
+        WriteStorage<'a, ManaPool>,
+        ReadStorage<'a, AreaOfEffect>,
+        ReadStorage<'a, Name>,
+        ReadStorage<'a, Point>,
+        WriteExpect<'a, EffectQueue>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            player_entity,
+            map,
+            mut wants_cast,
+            spells,
+            mut mana_pools,
+            aoe,
+            names,
+            positions,
+            mut effects,
+        ) = data;
+
+        for (entity, cast) in (&entities, &wants_cast).join() {
+            let (spell, pool) = match (spells.get(cast.spell), mana_pools.get_mut(entity)) {
+                (Some(spell), Some(pool)) if spell.owner == entity => (spell, pool),
+                _ => continue,
+            };
+
+            let spell_name = names.get(cast.spell).map_or("the spell", |name| name.0.as_str());
+            if pool.current < spell.mana_cost {
+                if entity == *player_entity {
+                    bo_logging::Logger::new()
+                        .append("You don't have enough mana to cast")
+                        .item_name(spell_name)
+                        .log();
+                }
+                continue;
+            }
+
+            pool.current -= spell.mana_cost;
+            if let Some(name) = names.get(entity) {
+                bo_logging::Logger::new().npc_name(&name.0).append("casts").item_name(spell_name).log();
+            }
+
+            effects.add_effect(
+                Some(entity),
+                EffectType::SpellUse(cast.spell),
+                match cast.target {
+                    None => Targets::Single(entity),
+                    Some(target) => {
+                        if let Some(aoe) = aoe.get(cast.spell) {
+                            let origin = positions.get(entity).copied().unwrap_or(target);
+                            Targets::Tiles(aoe_tiles(&map, origin, target, aoe))
+                        } else {
+                            Targets::Tile(map.point2d_to_index(target))
+                        }
+                    }
+                },
+            );
+        }
+
+        wants_cast.clear();
+    }
+}
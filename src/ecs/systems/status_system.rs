@@ -0,0 +1,115 @@
+use super::*;
+
+/// Runs every status on an entity once per turn it takes: fires on-tick effects, counts down
+/// durations, and cleans up whatever has expired. The player holds on to its turn while the game
+/// waits for input, so statuses only count down as turns are handed out.
+pub struct StatusEffectSystem {}
+
+impl<'a> System<'a> for StatusEffectSystem {
+    #[allow(clippy::type_complexity)]
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, TurnState>,
+        ReadStorage<'a, MyTurn>,
+        ReadStorage<'a, Name>,
+        WriteStorage<'a, StatusEffects>,
+        WriteStorage<'a, StatsChanged>,
+        WriteStorage<'a, FieldOfView>,
+        WriteExpect<'a, EffectQueue>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            turn_state,
+            turns,
+            names,
+            mut status_effects,
+            mut stats_changed,
+            mut fovs,
+            mut effects,
+        ) = data;
+
+        if *turn_state != TurnState::Ticking {
+            return;
+        }
+
+        let mut cured: Vec<Entity> = Vec::new();
+        for (entity, _turn, statuses) in (&entities, &turns, &mut status_effects).join() {
+            for status in statuses.effects.iter_mut() {
+                // On-tick hook
+                if let Some(effect) = status.kind.tick_effect(status.magnitude) {
+                    effects.add_effect(None, effect, Targets::Single(entity));
+                }
+
+                status.turns -= 1;
+            }
+
+            // On-expire hook
+            for expired in statuses.effects.iter().filter(|status| status.turns < 1) {
+                let kind = expired.kind;
+
+                if let Some(name) = names.get(entity) {
+                    bo_logging::Logger::new().npc_name(&name.0).append(kind.expire_message()).log();
+                }
+
+                if kind.changes_stats() {
+                    stats_changed.insert(entity, StatsChanged {}).expect("Unable to insert marker");
+                }
+
+                if kind.changes_sight() {
+                    if let Some(fov) = fovs.get_mut(entity) {
+                        fov.is_dirty = true;
+                    }
+                }
+            }
+
+            statuses.effects.retain(|status| status.turns > 0);
+            if statuses.effects.is_empty() {
+                cured.push(entity);
+            }
+        }
+
+        for entity in cured {
+            status_effects.remove(entity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_status_lasts_as_many_turns_as_it_was_given() {
+        let mut world = World::new();
+        world.register::<MyTurn>();
+        world.register::<Name>();
+        world.register::<StatusEffects>();
+        world.register::<StatsChanged>();
+        world.register::<FieldOfView>();
+        world.insert(EffectQueue::new());
+
+        let mut statuses = StatusEffects::default();
+        statuses.apply(StatusKind::Confusion, 3, 0);
+        let player = world.create_entity().with(statuses).with(MyTurn {}).build();
+
+        for turn in 1..=3 {
+            assert!(world.read_storage::<StatusEffects>().contains(player), "cured before turn {}", turn);
+
+            // The turn is handed out, then the game waits on the player for a good while
+            for state in [
+                TurnState::Ticking,
+                TurnState::AwaitingInput,
+                TurnState::AwaitingInput,
+                TurnState::PlayerTurn,
+            ] {
+                world.insert(state);
+                StatusEffectSystem {}.run_now(&world);
+                world.maintain();
+            }
+        }
+
+        assert!(!world.read_storage::<StatusEffects>().contains(player));
+    }
+}
@@ -12,10 +12,12 @@ impl<'a> System<'a> for TriggerSystem {
         ReadStorage<'a, Name>,
         Entities<'a>,
         ReadStorage<'a, AreaOfEffect>,
+        WriteExpect<'a, EffectQueue>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (map, mut entity_moved, position, entry_trigger, names, entities, area_of_effect) = data;
+        let (map, mut entity_moved, position, entry_trigger, names, entities, area_of_effect, mut effects) =
+            data;
 
         // Iterate the entities that moved and their final position
         for (entity, mut _entity_moved, pos) in (&entities, &mut entity_moved, &position).join() {
@@ -37,11 +39,12 @@ impl<'a> System<'a> for TriggerSystem {
                             }
 
                             // Call the effects system
-                            add_effect(
+                            effects.add_effect(
                                 Some(entity),
                                 EffectType::TriggerFire(entity_id),
                                 if let Some(aoe) = area_of_effect.get(entity_id) {
-                                    Targets::Tiles(aoe_tiles(&map, *pos, aoe.radius))
+                                    // Traps aim at themselves
+                                    Targets::Tiles(aoe_tiles(&map, *pos, *pos, aoe))
                                 } else {
                                     Targets::Tile(idx)
                                 },
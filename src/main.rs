@@ -100,6 +100,8 @@ impl GameWorld {
         GameWorld::register_components(&mut world);
 
         world.insert(EffectQueue::new());
+        world.insert(EffectRegistry::new());
+        world.insert(raws::get_hunger_config(&raws::RAWS.lock()));
         world.insert(modes::MenuMemory::new());
         world.insert(rex_assets::RexAssets::new());
 
@@ -131,12 +133,26 @@ impl GameWorld {
         world.register::<FieldOfView>();
         world.register::<Description>();
         world.register::<CombatStats>();
+        world.register::<Attributes>();
+        world.register::<DerivedStats>();
+        world.register::<Experience>();
+        world.register::<StatusEffects>();
+        world.register::<StatsChanged>();
         world.register::<EntityMoved>();
         world.register::<BlocksVisibility>();
         world.register::<OtherLevelPosition>();
 
+        // AI
+        world.register::<Faction>();
+        world.register::<Follower>();
+        world.register::<MonsterAI>();
+        world.register::<MyTurn>();
+        world.register::<Initiative>();
+
         // Intent
         world.register::<WantsToMelee>();
+        world.register::<WantsToShoot>();
+        world.register::<WantsToCastSpell>();
         world.register::<WantsToUseItem>();
         world.register::<WantsToDropItem>();
         world.register::<WantsToPickupItem>();
@@ -144,16 +160,47 @@ impl GameWorld {
         // Combat
         world.register::<HungerClock>();
         world.register::<ProvidesFood>();
+        world.register::<AttackCost>();
+        world.register::<TwoHanded>();
+        world.register::<Cursed>();
+        world.register::<MeleeDamage>();
+        world.register::<Resistances>();
+        world.register::<RangedAttack>();
+        world.register::<RangedWeapon>();
         world.register::<DefenseBonus>();
         world.register::<MeleePowerBonus>();
 
         // Items / Equipment
-        world.register::<Confusion>();
+        world.register::<InflictsStatus>();
         world.register::<InBackpack>();
         world.register::<Equippable>();
+        world.register::<AttributeBonus>();
         world.register::<MagicMapper>();
+        world.register::<ProvidesIdentification>();
+        world.register::<ProvidesRemoveCurse>();
+        world.register::<Charges>();
+        world.register::<ProvidesRecharge>();
+        world.register::<Ammunition>();
+        world.register::<LootTable>();
+        world.register::<LeavesCorpse>();
         world.register::<InflictsDamage>();
         world.register::<ProvidesHealing>();
+        world.register::<ProvidesMana>();
+        world.register::<ManaPool>();
+        world.register::<Spell>();
+        world.register::<TeachesSpell>();
+
+        // Movement
+        world.register::<Teleports>();
+        world.register::<TeleportsToTarget>();
+        world.register::<Blinks>();
+        world.register::<KnocksBack>();
+        world.register::<Pulls>();
+        world.register::<SwapsPlaces>();
+
+        // Hazards
+        world.register::<CreatesHazard>();
+        world.register::<Douses>();
 
         // Triggers
         world.register::<EntryTrigger>();
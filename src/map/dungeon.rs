@@ -17,23 +17,49 @@ pub struct MasterDungeonMap {
 
 impl MasterDungeonMap {
     pub fn new() -> MasterDungeonMap {
-        MasterDungeonMap {
+        let mut dm = MasterDungeonMap {
             maps: HashMap::new(),
             identified_items: HashSet::new(),
             scroll_mappings: HashMap::new(),
             potion_mappings: HashMap::new(),
+        };
+
+        let mut used_scroll_names: HashSet<String> = HashSet::new();
+        for scroll_tag in crate::raws::get_scroll_tags().iter() {
+            let masked_name = dm.make_scroll_name(&mut used_scroll_names);
+            dm.scroll_mappings.insert(scroll_tag.to_string(), masked_name);
+        }
+
+        let mut used_potion_names: HashSet<String> = HashSet::new();
+        for potion_tag in crate::raws::get_potion_tags().iter() {
+            let masked_name = dm.make_potion_name(&mut used_potion_names);
+            dm.potion_mappings.insert(potion_tag.to_string(), masked_name);
         }
 
-        // for scroll_tag in crate::raws::get_scroll_tags().iter() {
-        //     let masked_name = dm.make_scroll_name();
-        //     dm.scroll_mappings.insert(scroll_tag.to_string(), masked_name);
-        // }
+        dm
+    }
+
+    pub fn is_identified(&self, name: &str) -> bool {
+        self.identified_items.contains(name)
+            || (!self.scroll_mappings.contains_key(name) && !self.potion_mappings.contains_key(name))
+    }
+
+    /// Reveals an item's true name for the rest of the run. Returns false if it was already known.
+    pub fn identify(&mut self, name: &str) -> bool {
+        !self.is_identified(name) && self.identified_items.insert(name.to_string())
+    }
+
+    /// The name an item goes by: its real one once identified, its randomised one until then
+    pub fn display_name(&self, name: &str) -> String {
+        if self.is_identified(name) {
+            return name.to_string();
+        }
 
-        // let mut used_potion_names: HashSet<String> = HashSet::new();
-        // for potion_tag in crate::raws::get_potion_tags().iter() {
-        //     let masked_name = dm.make_potion_name(&mut used_potion_names);
-        //     dm.potion_mappings.insert(potion_tag.to_string(), masked_name);
-        // }
+        self.scroll_mappings
+            .get(name)
+            .or_else(|| self.potion_mappings.get(name))
+            .cloned()
+            .unwrap_or_default()
     }
 
     pub fn store_map(&mut self, map: &Map) { self.maps.insert(map.depth, map.clone()); }
@@ -114,7 +140,16 @@ impl MasterDungeonMap {
 }
 
 impl MasterDungeonMap {
-    fn _make_scroll_name(&self) -> String {
+    fn make_scroll_name(&self, used_names: &mut HashSet<String>) -> String {
+        loop {
+            let name = self.roll_scroll_name();
+            if used_names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn roll_scroll_name(&self) -> String {
         let length = 4 + crate::utils::rng::roll_dice(1, 4);
         let mut name = "Scroll of ".to_string();
 
@@ -157,7 +192,7 @@ impl MasterDungeonMap {
         name
     }
 
-    fn _make_potion_name(&self, used_names: &mut HashSet<String>) -> String {
+    fn make_potion_name(&self, used_names: &mut HashSet<String>) -> String {
         loop {
             let mut name: String = POTION_ADJECTIVES
                 [crate::utils::rng::roll_dice(1, POTION_ADJECTIVES.len() as i32) as usize - 1]
@@ -219,7 +254,7 @@ impl MasterDungeonMap {
             .with(player_start)
             .with(Glyph::new(to_cp437(')'), ColorPair::new(CYAN3, BLACK), RenderOrder::Item))
             .with(Name::new("Scroll of Magic Mapping"))
-            .with(Item {})
+            .with(Item::new(0.5, 50))
             .with(MagicMapper {})
             .with(Consumable {})
             .build();
@@ -229,11 +264,11 @@ impl MasterDungeonMap {
             .with(player_start)
             .with(Glyph::new(to_cp437(')'), ColorPair::new(CYAN, BLACK), RenderOrder::Item))
             .with(Name::new("Fireball Scroll"))
-            .with(Item {})
+            .with(Item::new(0.5, 50))
             .with(Consumable {})
             .with(Ranged(6))
-            .with(InflictsDamage(20))
-            .with(AreaOfEffect::new(3))
+            .with(InflictsDamage::new(20, DamageType::Fire))
+            .with(AreaOfEffect::new(AoeShape::Circle, 3))
             .marked::<SimpleMarker<SerializeMe>>()
             .build();
 
@@ -279,3 +314,18 @@ impl MasterDungeonMap {
         }
     }
 }
+
+/// Shorthand for [`MasterDungeonMap::display_name`] on an item entity, flagging any curse the
+/// player has found out about
+pub fn get_item_display_name(ecs: &World, item: Entity) -> String {
+    let name = match ecs.read_storage::<Name>().get(item) {
+        Some(name) => ecs.fetch::<MasterDungeonMap>().display_name(&name.0),
+        None => return "Unknown".to_string(),
+    };
+
+    if ecs.read_storage::<Cursed>().get(item).is_some_and(|curse| curse.known) {
+        format!("{} (cursed)", name)
+    } else {
+        name
+    }
+}
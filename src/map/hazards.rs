@@ -0,0 +1,72 @@
+use crate::prelude::*;
+
+/// Something dangerous lingering on a tile
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Serialize, Deserialize, Debug)]
+pub enum HazardKind {
+    /// Burns whoever stands in it, and spreads over anything that will catch
+    Fire,
+    /// Poisons whoever breathes it, drifting about until it thins away
+    Gas,
+}
+
+impl HazardKind {
+    pub fn color(&self) -> (u8, u8, u8) {
+        match self {
+            HazardKind::Fire => ORANGE_RED,
+            HazardKind::Gas => DARK_OLIVE_GREEN,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Serialize, Deserialize, Debug)]
+pub struct Hazard {
+    pub kind: HazardKind,
+    /// Turns left before it burns out or blows away
+    pub turns: i32,
+}
+
+impl Map {
+    pub fn is_flammable(&self, idx: usize) -> bool {
+        matches!(self.tiles[idx].tile_type, TileType::Grass | TileType::WoodFloor)
+    }
+
+    pub fn is_water(&self, idx: usize) -> bool {
+        matches!(self.tiles[idx].tile_type, TileType::ShallowWater | TileType::DeepWater)
+    }
+
+    /// Leaves a hazard on a tile, unless the tile won't hold it. Fire goes out in water and burns
+    /// away any gas; gas can't smother a fire. Returns whether the hazard took.
+    pub fn add_hazard(&mut self, idx: usize, kind: HazardKind, turns: i32) -> bool {
+        if !self.tiles[idx].walkable || turns < 1 {
+            return false;
+        }
+
+        match kind {
+            HazardKind::Fire if self.is_water(idx) => return false,
+            HazardKind::Gas
+                if self.hazards.get(&idx).is_some_and(|hazard| hazard.kind == HazardKind::Fire) =>
+            {
+                return false
+            }
+            _ => {}
+        }
+
+        let turns = match self.hazards.get(&idx) {
+            Some(existing) if existing.kind == kind => i32::max(existing.turns, turns),
+            _ => turns,
+        };
+
+        self.hazards.insert(idx, Hazard { kind, turns });
+        true
+    }
+
+    /// Puts out any fire on a tile. Returns whether there was one.
+    pub fn douse(&mut self, idx: usize) -> bool {
+        if self.hazards.get(&idx).is_some_and(|hazard| hazard.kind == HazardKind::Fire) {
+            self.hazards.remove(&idx);
+            return true;
+        }
+
+        false
+    }
+}
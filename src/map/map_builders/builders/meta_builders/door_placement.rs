@@ -10,13 +10,7 @@ impl DoorPlacement {
     pub fn new() -> Box<DoorPlacement> { Box::new(DoorPlacement {}) }
 
     fn door_possible(&self, build_data: &mut BuilderMap, idx: usize) -> bool {
-        let mut blocked = false;
-        for spawn in build_data.spawn_list.iter() {
-            if spawn.0 == idx {
-                blocked = true;
-            }
-        }
-        if blocked {
+        if build_data.is_spawn_tile(idx) {
             return false;
         }
 
@@ -12,7 +12,13 @@ impl RoomBasedSpawner {
     fn build(&mut self, build_data: &mut BuilderMap) {
         if let Some(rooms) = &build_data.rooms {
             for room in rooms.iter().skip(1) {
-                spawner::spawn_room(&build_data.map, room, build_data.map.depth, &mut build_data.spawn_list);
+                spawner::spawn_room(
+                    &build_data.map,
+                    room,
+                    build_data.map.depth,
+                    &mut build_data.spawn_list,
+                    &mut build_data.spawn_groups,
+                );
             }
         } else {
             panic!("Room Based Spawning only works after rooms have been created");
@@ -36,7 +36,13 @@ impl VoronoiSpawning {
 
         // Spawn the entities
         for area in noise_areas.iter() {
-            spawner::spawn_region(area.1, build_data.map.depth, &mut build_data.spawn_list);
+            spawner::spawn_region(
+                &build_data.map,
+                area.1,
+                build_data.map.depth,
+                &mut build_data.spawn_list,
+                &mut build_data.spawn_groups,
+            );
         }
     }
 }
@@ -155,9 +155,9 @@ impl PrefabBuilder {
         F: FnMut(i32, i32) -> bool,
     {
         let width = build_data.map.width;
-        build_data.spawn_list.retain(|(idx, _name)| {
-            let x = *idx as i32 % width;
-            let y = *idx as i32 / width;
+        build_data.retain_spawns(|idx| {
+            let x = idx as i32 % width;
+            let y = idx as i32 / width;
             filter(x, y)
         });
         build_data.take_snapshot();
@@ -299,8 +299,8 @@ impl PrefabBuilder {
 
                 let width = build_data.map.width; // The borrow checker really doesn't like it
                 let height = build_data.map.height; // when we access `self` inside the `retain`
-                build_data.spawn_list.retain(|e| {
-                    let idx = e.0 as i32;
+                build_data.retain_spawns(|idx| {
+                    let idx = idx as i32;
                     let x = idx % width;
                     let y = idx / height;
                     x < chunk_x
@@ -32,6 +32,7 @@ impl WaveformCollapseBuilder {
         build_data.map =
             Map::new(build_data.map.depth, build_data.width, build_data.height, &build_data.map.name);
         build_data.spawn_list.clear();
+        build_data.spawn_groups.clear();
         build_data.rooms = None;
         build_data.corridors = None;
         let mut tries = 0;
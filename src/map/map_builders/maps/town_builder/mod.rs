@@ -65,6 +65,7 @@ impl TownBuilder {
 
         self.spawn_dockers(build_data);
         self.spawn_townsfolk(build_data, &mut available_building_tiles);
+        self.spawn_guards(build_data);
 
         // Make visible for screenshot
         build_data.take_snapshot();
@@ -33,4 +33,12 @@ impl TownBuilder {
             }
         }
     }
+
+    pub fn spawn_guards(&mut self, build_data: &mut BuilderMap) {
+        for (idx, tt) in build_data.map.tiles.iter().enumerate() {
+            if tt.tile_type == TileType::Gravel && crate::rng::roll_dice(1, 40) == 1 {
+                build_data.spawn_list.push((idx, TOWN_GUARD.to_string()));
+            }
+        }
+    }
 }
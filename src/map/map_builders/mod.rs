@@ -24,6 +24,19 @@ pub trait MetaMapBuilder {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// A pack's leader and followers, each as the tile it spawns on and what spawns there
+#[derive(Debug, Clone)]
+pub struct SpawnGroup {
+    pub leader: (usize, String),
+    pub followers: Vec<(usize, String)>,
+}
+
+impl SpawnGroup {
+    pub fn tiles(&self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::once(self.leader.0).chain(self.followers.iter().map(|(idx, _)| *idx))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BuilderMap {
     pub map: Map,
@@ -32,11 +45,31 @@ pub struct BuilderMap {
     pub history: Vec<Map>,
     pub rooms: Option<Vec<Rect>>,
     pub spawn_list: Vec<(usize, String)>,
+    /// Packs, kept apart from `spawn_list` so that every follower stays with its own leader
+    pub spawn_groups: Vec<SpawnGroup>,
     pub starting_position: Option<Point>,
     pub corridors: Option<Vec<Vec<usize>>>,
 }
 
 impl BuilderMap {
+    /// Whether anything, pack or otherwise, is due to spawn on `idx`
+    pub fn is_spawn_tile(&self, idx: usize) -> bool {
+        self.spawn_list.iter().any(|(spawn_idx, _)| *spawn_idx == idx)
+            || self.spawn_groups.iter().any(|group| group.tiles().any(|tile| tile == idx))
+    }
+
+    /// Drops every spawn whose tile fails `keep`. A pack goes with its leader.
+    pub fn retain_spawns<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(usize) -> bool,
+    {
+        self.spawn_list.retain(|(idx, _)| keep(*idx));
+        self.spawn_groups.retain_mut(|group| {
+            group.followers.retain(|(idx, _)| keep(*idx));
+            keep(group.leader.0)
+        });
+    }
+
     fn take_snapshot(&mut self) {
         if SHOW_MAPGEN_VISUALIZER {
             let mut snapshot = self.map.clone();
@@ -64,6 +97,7 @@ impl BuilderChain {
                 corridors: None,
                 history: Vec::new(),
                 spawn_list: Vec::new(),
+                spawn_groups: Vec::new(),
                 starting_position: None,
                 map: Map::new(new_depth, width, height, name),
             },
@@ -98,6 +132,22 @@ impl BuilderChain {
         for entity in self.build_data.spawn_list.iter() {
             spawner::spawn_entity(world, &(&entity.0, &entity.1));
         }
+
+        for group in self.build_data.spawn_groups.iter() {
+            let leader = match spawner::spawn_entity(world, &(&group.leader.0, &group.leader.1)) {
+                Some(leader) => leader,
+                None => continue,
+            };
+
+            for follower in group.followers.iter() {
+                if let Some(follower) = spawner::spawn_entity(world, &(&follower.0, &follower.1)) {
+                    world
+                        .write_storage::<Follower>()
+                        .insert(follower, Follower::new(leader))
+                        .expect("Unable to insert follower");
+                }
+            }
+        }
     }
 }
 
@@ -1,19 +1,21 @@
 #![allow(clippy::module_inception)]
 
 use crate::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 pub mod map_builders;
 pub mod spatial;
 
 mod bitgrid;
 mod dungeon;
+mod hazards;
 mod themes;
 mod tiletype;
 
 pub use bitgrid::*;
 pub use dungeon::*;
-pub use map_builders::BuilderMap;
+pub use hazards::*;
+pub use map_builders::{BuilderMap, SpawnGroup};
 pub use themes::*;
 pub use tiletype::*;
 
@@ -27,6 +29,8 @@ pub struct Map {
     pub revealed: BitGrid,
     pub tiles: Vec<GameTile>,
     pub bloodstains: HashMap<usize, RGB>,
+    /// Kept in tile order, so hazards tick (and roll the rng) in the same order every time
+    pub hazards: BTreeMap<usize, Hazard>,
 }
 
 impl Map {
@@ -67,6 +71,7 @@ impl Map {
             depth: new_depth,
             name: name.to_string(),
             bloodstains: HashMap::new(),
+            hazards: BTreeMap::new(),
             visible: BitGrid::new(width, height),
             revealed: BitGrid::new(width, height),
             tiles: vec![GameTile::wall(); map_tile_count],
@@ -85,6 +90,10 @@ impl Map {
             color.bg = (*self.bloodstains.get(&idx).unwrap()).into();
         }
 
+        if let Some(hazard) = self.hazards.get(&idx) {
+            color.bg = RGB::named(hazard.kind.color()).into();
+        }
+
         if !self.visible.get_bit(self.index_to_point2d(idx)) {
             color.fg = color.fg.to_greyscale();
             color.bg = RGBA::from_f32(0., 0., 0., 0.); // Don't show stains out of visual range
@@ -108,6 +117,24 @@ impl Map {
     }
 }
 
+#[cfg(test)]
+impl Map {
+    /// A walled box of open floor, with the spatial map set up to match
+    pub fn test_room(width: i32, height: i32) -> Map {
+        let mut map = Map::new(1, width, height, "Test Room");
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                let idx = map.xy_idx(x, y);
+                map.tiles[idx] = GameTile::floor();
+            }
+        }
+
+        crate::spatial::populate_blocked_from_map(&map);
+        crate::spatial::populate_opaque_from_map(&map);
+        map
+    }
+}
+
 impl Algorithm2D for Map {
     fn dimensions(&self) -> Point { Point::new(self.width, self.height) }
 
@@ -24,6 +24,10 @@ impl SpatialMap {
 
 static SPATIAL_MAP: Mutex<SpatialMap> = Mutex::new(SpatialMap::new());
 
+/// The spatial map is shared by the whole process, so tests that build a map take turns with it
+#[cfg(test)]
+pub static TEST_LOCK: Mutex<()> = Mutex::new(());
+
 pub fn set_size(width: i32, height: i32) {
     let mut lock = SPATIAL_MAP.lock();
     lock.width = width;
@@ -212,6 +216,12 @@ where
     default
 }
 
+/// The first entity standing in the way on this tile, if any
+pub fn get_blocking_entity(idx: usize) -> Option<Entity> {
+    let lock = SPATIAL_MAP.lock();
+    lock.tile_content[idx].iter().find(|(_, blocks, _)| *blocks).map(|(e, _, _)| *e)
+}
+
 pub fn get_tile_content_clone(idx: usize) -> Vec<Entity> {
     let lock = SPATIAL_MAP.lock();
     lock.tile_content[idx].iter().map(|(e, _, _)| *e).collect()
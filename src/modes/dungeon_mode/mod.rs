@@ -110,6 +110,19 @@ impl DungeonMode {
                         *runwriter = TurnState::PlayerTurn;
                     }
                 },
+                // Spellbook
+                ModeResult::SpellbookModeResult(result) => match result {
+                    SpellbookModeResult::Cancelled => {}
+                    SpellbookModeResult::Cast(spell, target) => {
+                        self.cast_spell(world, spell, *target);
+
+                        let mut runwriter = world.write_resource::<TurnState>();
+                        *runwriter = TurnState::PlayerTurn;
+                    }
+                },
+                // Level Up
+                ModeResult::LevelUpModeResult(LevelUpModeResult::Done) => {}
+
                 _ => unreachable!("Unknown popped dungeon result: [{:?}]", result),
             };
         }
@@ -125,9 +138,13 @@ impl DungeonMode {
                 return (ModeControl::Switch(GameOverMode::new().into()), ModeUpdate::Immediate)
             }
             TurnState::MagicMapReveal(row) => self.reveal_map(world, row),
-            TurnState::PreRun | TurnState::PlayerTurn | TurnState::MonsterTurn => {
+            TurnState::PreRun | TurnState::PlayerTurn | TurnState::Ticking => {
                 self.run_dispatcher(world);
             }
+            TurnState::AwaitingInput if self.is_paralyzed(world) => self.end_turn(world),
+            TurnState::AwaitingInput if self.has_pending_level_up(world) => {
+                return (ModeControl::Push(LevelUpMode::new().into()), ModeUpdate::Update)
+            }
             TurnState::AwaitingInput => match player_input(ctx, world) {
                 player::PlayerInputResult::NoResult => {}
                 player::PlayerInputResult::AppQuit => return self.app_quit_dialog(),
@@ -135,6 +152,9 @@ impl DungeonMode {
                 player::PlayerInputResult::ShowInventory => {
                     return (ModeControl::Push(InventoryMode::new(world).into()), ModeUpdate::Update)
                 }
+                player::PlayerInputResult::ShowSpellbook => {
+                    return (ModeControl::Push(SpellbookMode::new(world).into()), ModeUpdate::Update)
+                }
                 player::PlayerInputResult::Descend => {
                     return (
                         ModeControl::Push(
@@ -174,6 +194,20 @@ impl DungeonMode {
         return (ModeControl::Stay, ModeUpdate::Update);
     }
 
+    fn is_paralyzed(&self, world: &World) -> bool {
+        world
+            .read_storage::<StatusEffects>()
+            .get(*world.fetch::<Entity>())
+            .is_some_and(|statuses| statuses.prevents_action())
+    }
+
+    fn has_pending_level_up(&self, world: &World) -> bool {
+        world
+            .read_storage::<Experience>()
+            .get(*world.fetch::<Entity>())
+            .is_some_and(|experience| experience.pending_choices > 0)
+    }
+
     fn end_turn(&self, world: &World) {
         bo_logging::record_event(TURN_DONE_EVENT, 1);
         let mut runwriter = world.write_resource::<TurnState>();
@@ -187,6 +221,13 @@ impl DungeonMode {
             .expect("Failed to insert intent");
     }
 
+    fn cast_spell(&self, world: &World, spell: &Entity, pt: Option<Point>) {
+        world
+            .write_storage::<WantsToCastSpell>()
+            .insert(*world.fetch::<Entity>(), WantsToCastSpell::new(*spell, pt))
+            .expect("Failed to insert intent");
+    }
+
     fn drop_item(&self, world: &World, item: &Entity) {
         world
             .write_storage::<WantsToDropItem>()
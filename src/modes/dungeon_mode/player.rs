@@ -10,6 +10,8 @@ pub enum PlayerInputResult {
     ShowRemove,
     ShowInventory,
     _ShowInventoryShortcut,
+    // Magic
+    ShowSpellbook,
 }
 
 #[rustfmt::skip]
@@ -36,6 +38,12 @@ pub fn player_input(ctx: &mut BTerm, world: &mut World) -> PlayerInputResult {
             GameKey::Drop => return PlayerInputResult::ShowDrop,
             GameKey::Remove => return PlayerInputResult::ShowRemove,
 
+            // Ranged
+            GameKey::Fire => return fire(world),
+
+            // Magic
+            GameKey::Cast => return PlayerInputResult::ShowSpellbook,
+
             // Stairs
             GameKey::TakeStairs => {
                 if try_next_level(world) { return PlayerInputResult::Descend; }
@@ -52,6 +60,27 @@ pub fn player_input(ctx: &mut BTerm, world: &mut World) -> PlayerInputResult {
 }
 
 pub fn try_move_player(delta_pt: Point, world: &mut World) {
+    // A confused player staggers off in a random direction half the time
+    let player_entity = *world.fetch::<Entity>();
+    let confused = world
+        .read_storage::<StatusEffects>()
+        .get(player_entity)
+        .is_some_and(|statuses| statuses.has(StatusKind::Confusion));
+    let delta_pt = if confused && crate::rng::roll_dice(1, 2) == 1 {
+        match crate::rng::range(0, 8) {
+            0 => Point::new(-1, -1),
+            1 => Point::new(0, -1),
+            2 => Point::new(1, -1),
+            3 => Point::new(-1, 0),
+            4 => Point::new(1, 0),
+            5 => Point::new(-1, 1),
+            6 => Point::new(0, 1),
+            _ => Point::new(1, 1),
+        }
+    } else {
+        delta_pt
+    };
+
     let map = world.fetch::<Map>();
     let entities = world.entities();
     let players = world.read_storage::<Player>();
@@ -125,6 +154,74 @@ fn get_item(world: &mut World) {
     }
 }
 
+/// Fires the equipped ranged weapon at the nearest hostile in view
+fn fire(world: &mut World) -> PlayerInputResult {
+    let map = world.fetch::<Map>();
+    let player = *world.fetch::<Entity>();
+    let player_pos = *world.fetch::<Point>();
+
+    let names = world.read_storage::<Name>();
+    let equipped = world.read_storage::<Equipped>();
+    let backpack = world.read_storage::<InBackpack>();
+    let ammunition = world.read_storage::<Ammunition>();
+    let ranged_weapons = world.read_storage::<RangedWeapon>();
+
+    let weapon = match (&ranged_weapons, &equipped).join().find(|(_, equip)| equip.owner == player) {
+        Some((weapon, _)) => weapon,
+        None => {
+            bo_logging::Logger::new().append("You have no ranged weapon equipped.").log();
+            return PlayerInputResult::NoResult;
+        }
+    };
+
+    if let Some(kind) = &weapon.ammo {
+        let has_ammo = (&ammunition, &backpack)
+            .join()
+            .any(|(ammo, pack)| pack.owner == player && ammo.kind == *kind && ammo.count > 0);
+
+        if !has_ammo {
+            bo_logging::Logger::new().append("You are out of ammunition.").log();
+            return PlayerInputResult::NoResult;
+        }
+    }
+
+    let fovs = world.read_storage::<FieldOfView>();
+    let factions = world.read_storage::<Faction>();
+    let combat_stats = world.read_storage::<CombatStats>();
+    let target = match (fovs.get(player), factions.get(player)) {
+        (Some(fov), Some(faction)) => {
+            crate::ecs::find_nearest_hostile(player, faction, player_pos, fov, &factions, &combat_stats)
+        }
+        _ => None,
+    };
+
+    let (target, target_pos) = match target {
+        Some(target) => target,
+        None => {
+            bo_logging::Logger::new().append("There is nothing to shoot at.").log();
+            return PlayerInputResult::NoResult;
+        }
+    };
+
+    let target_name = names.get(target).map_or("it", |name| name.0.as_str());
+    if DistanceAlg::Pythagoras.distance2d(player_pos, target_pos) > weapon.range as f32 {
+        bo_logging::Logger::new().npc_name(target_name).append("is out of range.").log();
+        return PlayerInputResult::NoResult;
+    }
+
+    if line_of_fire(&map, player_pos, target_pos).last() != Some(&target_pos) {
+        bo_logging::Logger::new().append("You don't have a clear shot at").npc_name(target_name).log();
+        return PlayerInputResult::NoResult;
+    }
+
+    world
+        .write_storage::<WantsToShoot>()
+        .insert(player, WantsToShoot::new(target))
+        .expect("Unable to insert intent");
+
+    PlayerInputResult::TurnDone
+}
+
 fn try_next_level(world: &mut World) -> bool {
     let map = world.fetch::<Map>();
     let player_pos = world.fetch::<Point>();
@@ -166,7 +263,7 @@ fn skip_turn(world: &mut World) -> PlayerInputResult {
     }
 
     if can_heal {
-        add_single_healing_effect(None, *player, 1);
+        world.write_resource::<EffectQueue>().add_single_healing_effect(None, *player, 1);
     }
 
     PlayerInputResult::TurnDone
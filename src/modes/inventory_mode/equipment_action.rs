@@ -65,12 +65,12 @@ impl EquipmentActionMode {
         let selection =
             default_action.and_then(|d_act| actions.iter().position(|a| *a == d_act)).unwrap_or(0);
 
-        let item_width = world.read_storage::<Name>().get(item_id).unwrap().0.len();
+        let item_name = get_item_display_name(world, item_id);
+        let item_width = item_name.len();
         let inner_width =
             2 + item_width.max(CANCEL.len()).max(actions.iter().map(|a| a.label().len()).max().unwrap_or(0));
 
         let item_glyph = *world.read_storage::<Glyph>().get(item_id).unwrap();
-        let item_name = world.read_storage::<Name>().get(item_id).unwrap().0.clone();
 
         Self {
             item_id,
@@ -37,7 +37,10 @@ impl InventoryAction {
     pub fn item_supports_action(world: &World, item: Entity, action: InventoryAction) -> bool {
         match action {
             InventoryAction::DropItem => true,
-            InventoryAction::UseItem => world.read_storage::<Consumable>().contains(item),
+            InventoryAction::UseItem => {
+                world.read_storage::<Consumable>().contains(item)
+                    || world.read_storage::<Charges>().contains(item)
+            }
             InventoryAction::EquipItem => world.read_storage::<Equippable>().contains(item),
         }
     }
@@ -81,13 +84,13 @@ impl InventoryActionMode {
             default_action.and_then(|d_act| actions.iter().position(|a| *a == d_act)).unwrap_or(0);
         let subsection = if actions.is_empty() { SubSection::Cancel } else { SubSection::Actions };
 
-        let item_width = world.read_storage::<Name>().get(item_id).unwrap().0.len();
+        let item_name = get_item_display_name(world, item_id);
+        let item_width = item_name.len();
         let inner_width =
             3 + item_width.max(CANCEL.len()).max(actions.iter().map(|a| a.label().len()).max().unwrap_or(0))
                 as i32;
 
         let item_glyph = *world.read_storage::<Glyph>().get(item_id).unwrap();
-        let item_name = world.read_storage::<Name>().get(item_id).unwrap().0.clone();
 
         Self { actions, subsection, selection, inner_width, item: (item_id, item_glyph, item_name) }
     }
@@ -6,10 +6,9 @@ pub mod inventory_action;
 pub use equipment_action::*;
 pub use inventory_action::*;
 
-const INVENTORY_BASE_WIDTH: i32 = 25;
+const INVENTORY_BASE_WIDTH: i32 = 36;
 const INVENTORY_BASE_HEIGHT: i32 = 4;
 const INVENTORY_EQUIPMENT_OFFSET: i32 = 9;
-const BASE_Y: i32 = 6;
 
 #[derive(Debug)]
 pub enum InventoryModeResult {
@@ -24,27 +23,23 @@ pub enum InventoryModeResult {
 #[derive(Debug)]
 enum SubSection {
     Inventory,
-    EquipArmor,
-    EquipWeapon,
+    Equipment(usize),
 }
 
-#[derive(Debug)]
-struct Equipment {
-    armor: Option<(Entity, String, Glyph)>,
-    weapon: Option<(Entity, String, Glyph)>,
-}
+/// An equipped item's entity, name and glyph
+type EquippedItem = (Entity, String, Glyph);
 
 #[derive(Debug)]
 pub struct InventoryMode {
-    equipment: Equipment,
+    equipment: Vec<(EquipmentSlot, Option<EquippedItem>)>,
+    /// Total weight carried, carry capacity and total value of everything the player has
+    totals: (f32, i32, i32),
     inv_selection: usize,
     dimensions: (i32, i32),
     subsection: SubSection,
     inventory: Vec<(Entity, String)>,
 }
 
-impl_new!(Equipment, weapon: Option<(Entity, String, Glyph)>, armor: Option<(Entity, String, Glyph)>);
-
 /// Show a screen with items carried by the player, and allow them to be manipulated.
 impl InventoryMode {
     pub fn new(world: &World) -> Self {
@@ -56,25 +51,52 @@ impl InventoryMode {
         let equipped = world.read_storage::<Equipped>();
         let backpack = world.read_storage::<InBackpack>();
 
-        let equipment = (&entities, &names, &glyphs, &equipped)
+        let worn = (&entities, &names, &glyphs, &equipped)
             .join()
             .filter(|(_, _, _, b)| b.owner == *player)
             .collect::<Vec<_>>();
 
-        let weapon = equipment
-            .iter()
-            .find(|(_, _, _, equip)| equip.slot == EquipmentSlot::Melee)
-            .map(|(e, name, glyph, _)| (*e, name.0.clone(), **glyph));
-
-        let armor = equipment
+        let equipment = EquipmentSlot::ALL
             .iter()
-            .find(|(_, _, _, equip)| equip.slot == EquipmentSlot::Shield)
-            .map(|(e, name, glyph, _)| (*e, name.0.clone(), **glyph));
+            .map(|slot| {
+                let item = worn
+                    .iter()
+                    .find(|(_, _, _, equip)| equip.slot == *slot)
+                    .map(|(e, _, glyph, _)| (*e, get_item_display_name(world, *e), **glyph));
+                (*slot, item)
+            })
+            .collect::<Vec<_>>();
 
+        let items = world.read_storage::<Item>();
+        let derived = world.read_storage::<DerivedStats>();
+        let total_value = (&items, &backpack)
+            .join()
+            .filter(|(_, b)| b.owner == *player)
+            .map(|(item, _)| item.base_value)
+            .chain(
+                (&items, &equipped)
+                    .join()
+                    .filter(|(_, b)| b.owner == *player)
+                    .map(|(item, _)| item.base_value),
+            )
+            .sum();
+        let totals = derived
+            .get(*player)
+            .map_or((0.0, 0, total_value), |stats| (stats.carried_weight, stats.carry_capacity, total_value));
+
+        // Bundles of ammunition show how many shots are left, and charged items how many uses
+        let ammunition = world.read_storage::<Ammunition>();
+        let charges = world.read_storage::<Charges>();
         let inventory = (&entities, &names, &backpack)
             .join()
             .filter(|(_, _, b)| b.owner == *player)
-            .map(|b| (b.0, b.1 .0.clone()))
+            .map(|(e, _, _)| {
+                let count = ammunition.get(e).map(|ammo| ammo.count).or(charges.get(e).map(|c| c.remaining));
+                match count {
+                    Some(count) => (e, format!("{} ({})", get_item_display_name(world, e), count)),
+                    None => (e, get_item_display_name(world, e)),
+                }
+            })
             .collect::<Vec<_>>();
 
         let inv_selection =
@@ -97,7 +119,8 @@ impl InventoryMode {
             inv_selection,
             subsection: SubSection::Inventory,
             dimensions: (inv_width, inv_height),
-            equipment: Equipment::new(weapon, armor),
+            equipment,
+            totals,
         }
     }
 
@@ -151,37 +174,28 @@ impl InventoryMode {
                     return (ModeControl::Pop(InventoryModeResult::DoNothing.into()), ModeUpdate::Update)
                 }
                 ////////////////////////////////////////////////////
-                // Sub Section Weapon
+                // Sub Section Equipment
                 ////////////////////////////////////////////////////
-                (SubSection::EquipWeapon, GameKey::Up) => {
-                    self.subsection = SubSection::Inventory;
-                    self.inv_selection = if self.inventory.is_empty() { 0 } else { self.inventory.len() - 1 }
-                }
-                (SubSection::EquipWeapon, GameKey::Down) => {
-                    self.subsection = SubSection::EquipArmor;
-                }
-                (SubSection::EquipWeapon, GameKey::Select) => {
-                    if let Some(weapon) = &self.equipment.weapon {
-                        return (
-                            ModeControl::Push(EquipmentActionMode::new(world, weapon.0, None).into()),
-                            ModeUpdate::Update,
-                        );
+                (SubSection::Equipment(slot), GameKey::Up) => {
+                    if *slot > 0 {
+                        self.subsection = SubSection::Equipment(slot - 1);
+                    } else {
+                        self.subsection = SubSection::Inventory;
+                        self.inv_selection = self.inventory.len().saturating_sub(1);
                     }
                 }
-                ////////////////////////////////////////////////////
-                // Sub Section Armor
-                ////////////////////////////////////////////////////
-                (SubSection::EquipArmor, GameKey::Up) => {
-                    self.subsection = SubSection::EquipWeapon;
-                }
-                (SubSection::EquipArmor, GameKey::Down) => {
-                    self.subsection = SubSection::Inventory;
-                    self.inv_selection = 0;
+                (SubSection::Equipment(slot), GameKey::Down) => {
+                    if *slot + 1 < self.equipment.len() {
+                        self.subsection = SubSection::Equipment(slot + 1);
+                    } else {
+                        self.subsection = SubSection::Inventory;
+                        self.inv_selection = 0;
+                    }
                 }
-                (SubSection::EquipArmor, GameKey::Select) => {
-                    if let Some(armor) = &self.equipment.armor {
+                (SubSection::Equipment(slot), GameKey::Select) => {
+                    if let Some((_, Some(item))) = self.equipment.get(*slot) {
                         return (
-                            ModeControl::Push(EquipmentActionMode::new(world, armor.0, None).into()),
+                            ModeControl::Push(EquipmentActionMode::new(world, item.0, None).into()),
                             ModeUpdate::Update,
                         );
                     }
@@ -193,14 +207,14 @@ impl InventoryMode {
                     if self.inv_selection > 0 {
                         self.inv_selection -= 1;
                     } else {
-                        self.subsection = SubSection::EquipArmor;
+                        self.subsection = SubSection::Equipment(self.equipment.len() - 1);
                     }
                 }
                 (SubSection::Inventory, GameKey::Down) => {
                     if !self.inventory.is_empty() && self.inv_selection < self.inventory.len() - 1 {
                         self.inv_selection += 1;
                     } else {
-                        self.subsection = SubSection::EquipWeapon;
+                        self.subsection = SubSection::Equipment(0);
                     }
                 }
                 (SubSection::Inventory, GameKey::Select) => {
@@ -253,69 +267,61 @@ impl InventoryMode {
         ////////////////////////////////////////////////////////////////////////
 
         let (inv_width, inv_height) = self.dimensions;
-        let weapon_bg_color =
-            if matches!(self.subsection, SubSection::EquipWeapon) { SELECTED_BG } else { BLACK };
-
-        let armor_bg_color =
-            if matches!(self.subsection, SubSection::EquipArmor) { SELECTED_BG } else { BLACK };
+        let equipment_height = self.equipment.len() as i32 + 4;
 
         let start_x = (MAP_PANEL_WIDTH / 2) - (inv_width / 2);
-        let start_y = (MAP_PANEL_HEIGHT / 2) - i32::max(BASE_Y, inv_height / 2);
+        let start_y = i32::max(1, (MAP_PANEL_HEIGHT - equipment_height - inv_height - 1) / 2);
         let equipment_box = box_with_title(
             draw_batch,
             Point::new(start_x, start_y),
             BoxConfigWithTitle::new(
-                BoxConfig::new((inv_width, 5), ColorPair::new(BOX_GRAY, BLACK), false, false),
+                BoxConfig::new((inv_width, equipment_height), ColorPair::new(BOX_GRAY, BLACK), false, false),
                 TextConfig::new("< Equipment >", ColorPair::new(CYAN, BLACK), Alignment::Left, false),
             ),
         );
 
         let eq_x = equipment_box.x1 + 1;
-        let mut eq_y = equipment_box.y1 + 2;
-
-        // Weapon
-        draw_batch.print_color(Point::new(eq_x, eq_y), "Weapon:", ColorPair::new(WHITE, BLACK));
-        if let Some(weapon) = &self.equipment.weapon {
-            draw_batch.set(
-                Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET - 1, eq_y),
-                weapon.2.color,
-                weapon.2.glyph,
-            );
-            draw_batch.print_color(
-                Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET + 1, eq_y),
-                weapon.1.clone(),
-                ColorPair::new(WHITE, weapon_bg_color),
-            );
-        } else {
-            draw_batch.print_color(
-                Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET, eq_y),
-                "-- No Weapon --",
-                ColorPair::new(WHITE, weapon_bg_color),
-            );
-        }
+        for (i, (slot, item)) in self.equipment.iter().enumerate() {
+            let eq_y = equipment_box.y1 + 2 + i as i32;
+            let bg_color = if matches!(self.subsection, SubSection::Equipment(selected) if selected == i) {
+                SELECTED_BG
+            } else {
+                BLACK
+            };
 
-        // Armor
-        eq_y += 1;
-        draw_batch.print_color(Point::new(eq_x, eq_y), "Armor:", ColorPair::new(WHITE, BLACK));
-        if let Some(armor) = &self.equipment.armor {
-            draw_batch.set(
-                Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET - 1, eq_y),
-                armor.2.color,
-                armor.2.glyph,
-            );
-            draw_batch.print_color(
-                Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET + 1, eq_y),
-                armor.1.clone(),
-                ColorPair::new(WHITE, armor_bg_color),
-            );
-        } else {
             draw_batch.print_color(
-                Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET, eq_y),
-                "-- No Armor --",
-                ColorPair::new(WHITE, armor_bg_color),
+                Point::new(eq_x, eq_y),
+                format!("{}:", slot.name()),
+                ColorPair::new(WHITE, BLACK),
             );
+            if let Some((_, name, glyph)) = item {
+                draw_batch.set(
+                    Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET - 1, eq_y),
+                    glyph.color,
+                    glyph.glyph,
+                );
+                draw_batch.print_color(
+                    Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET + 1, eq_y),
+                    name.clone(),
+                    ColorPair::new(WHITE, bg_color),
+                );
+            } else {
+                draw_batch.print_color(
+                    Point::new(eq_x + INVENTORY_EQUIPMENT_OFFSET, eq_y),
+                    "-- Empty --",
+                    ColorPair::new(WHITE, bg_color),
+                );
+            }
         }
 
+        // Totals
+        let (weight, capacity, value) = self.totals;
+        draw_batch.print_color(
+            Point::new(eq_x, equipment_box.y1 + 2 + self.equipment.len() as i32),
+            format!("Weight: {:.1}/{} lbs  Value: {} gp", weight, capacity, value),
+            ColorPair::new(GRAY, BLACK),
+        );
+
         equipment_box
     }
 
@@ -0,0 +1,140 @@
+use super::*;
+
+const TITLE: &str = "You feel more experienced!";
+
+#[derive(Debug)]
+pub enum LevelUpModeResult {
+    Done,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LevelUpChoice {
+    Might,
+    Fitness,
+    Quickness,
+    Intelligence,
+}
+
+impl LevelUpChoice {
+    fn label(&self) -> &'static str {
+        match self {
+            LevelUpChoice::Might => "[ Might: hit harder, carry more ]",
+            LevelUpChoice::Fitness => "[ Fitness: more hit points ]",
+            LevelUpChoice::Quickness => "[ Quickness: hit more often, act faster ]",
+            LevelUpChoice::Intelligence => "[ Intelligence: more mana, recovered faster ]",
+        }
+    }
+
+    fn apply(&self, attributes: &mut Attributes) -> &'static str {
+        let (attribute, message) = match self {
+            LevelUpChoice::Might => (&mut attributes.might, "You feel mightier."),
+            LevelUpChoice::Fitness => (&mut attributes.fitness, "You feel healthier."),
+            LevelUpChoice::Quickness => (&mut attributes.quickness, "You feel quicker."),
+            LevelUpChoice::Intelligence => (&mut attributes.intelligence, "You feel smarter."),
+        };
+
+        attribute.base += 1;
+        message
+    }
+}
+
+#[derive(Debug)]
+pub struct LevelUpMode {
+    selection: usize,
+    choices: Vec<LevelUpChoice>,
+}
+
+impl Default for LevelUpMode {
+    fn default() -> Self { Self::new() }
+}
+
+/// Let the player pick an attribute to improve after gaining a level.
+impl LevelUpMode {
+    pub fn new() -> Self {
+        let choices = vec![
+            LevelUpChoice::Might,
+            LevelUpChoice::Fitness,
+            LevelUpChoice::Quickness,
+            LevelUpChoice::Intelligence,
+        ];
+
+        Self { selection: 0, choices }
+    }
+
+    fn confirm_choice(&self, world: &World) -> (ModeControl, ModeUpdate) {
+        let player = *world.fetch::<Entity>();
+        let choice = self.choices[self.selection];
+
+        if let Some(attributes) = world.write_storage::<Attributes>().get_mut(player) {
+            let message = choice.apply(attributes);
+            bo_logging::Logger::new().color(MAGENTA).append(message).log();
+        }
+
+        if let Some(experience) = world.write_storage::<Experience>().get_mut(player) {
+            experience.pending_choices = i32::max(0, experience.pending_choices - 1);
+        }
+
+        world
+            .write_storage::<StatsChanged>()
+            .insert(player, StatsChanged {})
+            .expect("Unable to insert marker");
+
+        (ModeControl::Pop(LevelUpModeResult::Done.into()), ModeUpdate::Immediate)
+    }
+
+    pub fn tick(
+        &mut self,
+        ctx: &mut BTerm,
+        world: &mut World,
+        _pop_result: &Option<ModeResult>,
+    ) -> (ModeControl, ModeUpdate) {
+        if let Some(key) = ctx.get_key() {
+            match key {
+                GameKey::Down => self.selection = (self.selection + 1) % self.choices.len(),
+                GameKey::Up => {
+                    self.selection = (self.selection + self.choices.len() - 1) % self.choices.len()
+                }
+                GameKey::Select => return self.confirm_choice(world),
+                _ => {}
+            }
+        }
+
+        (ModeControl::Stay, ModeUpdate::Update)
+    }
+
+    pub fn draw(&self, _ctx: &mut BTerm, _world: &mut World, _active: bool) {
+        let mut draw_batch = DrawBatch::new();
+        draw_batch.target(LAYER_TEXT);
+
+        let inner_width =
+            4 + TITLE.len().max(self.choices.iter().map(|c| c.label().len()).max().unwrap_or(0)) as i32;
+
+        let box_rect = center_box(
+            &mut draw_batch,
+            (MAP_PANEL_WIDTH, MAP_PANEL_HEIGHT),
+            BoxConfig::new(
+                (inner_width, self.choices.len() as i32 + 4),
+                ColorPair::new(WHITE, BLACK),
+                true,
+                false,
+            ),
+        );
+
+        let center_x = box_rect.x1 + box_rect.width() / 2 + 1;
+        let y = box_rect.y1 + 1;
+
+        draw_batch.print_color_centered_at(Point::new(center_x, y), TITLE, ColorPair::new(MAGENTA, BLACK));
+
+        for (i, choice) in self.choices.iter().enumerate() {
+            let bg = if i == self.selection { crate::utils::SELECTED_BG } else { BLACK };
+
+            draw_batch.print_color_centered_at(
+                Point::new(center_x, y + 2 + i as i32),
+                choice.label(),
+                ColorPair::new(WHITE, bg),
+            );
+        }
+
+        draw_batch.submit(BATCH_UI_INV).expect("Batch error"); // On top of everything
+    }
+}
@@ -4,9 +4,11 @@ pub mod dialogs;
 pub mod dungeon_mode;
 pub mod game_over_mode;
 pub mod inventory_mode;
+pub mod level_up_mode;
 pub mod main_menu_mode;
 pub mod map_gen;
 pub mod menu_memory;
+pub mod spellbook_mode;
 pub mod targeting_mode;
 
 use app_quit_dialog::{AppQuitDialogMode, AppQuitDialogModeResult};
@@ -15,9 +17,11 @@ use game_over_mode::{GameOverMode, GameOverModeResult};
 use inventory_mode::{EquipmentActionMode, EquipmentActionModeResult};
 use inventory_mode::{InventoryActionMode, InventoryActionModeResult};
 use inventory_mode::{InventoryMode, InventoryModeResult};
+use level_up_mode::{LevelUpMode, LevelUpModeResult};
 
 use main_menu_mode::{MainMenuMode, MainMenuModeResult};
 use map_gen::{MapGenMode, MapGenModeResult};
+use spellbook_mode::{SpellbookMode, SpellbookModeResult};
 use targeting_mode::{TargetingMode, TargetingModeResult};
 
 use dialogs::*;
@@ -53,11 +57,13 @@ pub enum Mode {
     GameOverMode(GameOverMode),
     InventoryMode(InventoryMode),
     TargetingMode(TargetingMode),
+    LevelUpMode(LevelUpMode),
     MessageBoxMode(MessageBoxMode),
     YesNoDialogMode(YesNoDialogMode),
     AppQuitDialogMode(AppQuitDialogMode),
     InventoryActionMode(InventoryActionMode),
     EquipmentActionMode(EquipmentActionMode),
+    SpellbookMode(SpellbookMode),
 }
 
 impl_from!(Mode, MapGenMode);
@@ -66,11 +72,13 @@ impl_from!(Mode, MainMenuMode);
 impl_from!(Mode, GameOverMode);
 impl_from!(Mode, InventoryMode);
 impl_from!(Mode, TargetingMode);
+impl_from!(Mode, LevelUpMode);
 impl_from!(Mode, MessageBoxMode);
 impl_from!(Mode, YesNoDialogMode);
 impl_from!(Mode, AppQuitDialogMode);
 impl_from!(Mode, InventoryActionMode);
 impl_from!(Mode, EquipmentActionMode);
+impl_from!(Mode, SpellbookMode);
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -82,11 +90,13 @@ pub enum ModeResult {
     GameOverModeResult(GameOverModeResult),
     InventoryModeResult(InventoryModeResult),
     TargetingModeResult(TargetingModeResult),
+    LevelUpModeResult(LevelUpModeResult),
     MessageBoxModeResult(MessageBoxModeResult),
     YesNoDialogModeResult(YesNoDialogModeResult),
     AppQuitDialogModeResult(AppQuitDialogModeResult),
     InventoryActionModeResult(InventoryActionModeResult),
     EquipmentActionModeResult(EquipmentActionModeResult),
+    SpellbookModeResult(SpellbookModeResult),
 }
 
 impl_from!(ModeResult, MapGenModeResult);
@@ -95,11 +105,13 @@ impl_from!(ModeResult, MainMenuModeResult);
 impl_from!(ModeResult, GameOverModeResult);
 impl_from!(ModeResult, InventoryModeResult);
 impl_from!(ModeResult, TargetingModeResult);
+impl_from!(ModeResult, LevelUpModeResult);
 impl_from!(ModeResult, MessageBoxModeResult);
 impl_from!(ModeResult, YesNoDialogModeResult);
 impl_from!(ModeResult, AppQuitDialogModeResult);
 impl_from!(ModeResult, InventoryActionModeResult);
 impl_from!(ModeResult, EquipmentActionModeResult);
+impl_from!(ModeResult, SpellbookModeResult);
 
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -143,11 +155,13 @@ impl Mode {
             Mode::GameOverMode(x) => x.tick(ctx, world, pop_result),
             Mode::InventoryMode(x) => x.tick(ctx, world, pop_result),
             Mode::TargetingMode(x) => x.tick(ctx, world, pop_result),
+            Mode::LevelUpMode(x) => x.tick(ctx, world, pop_result),
             Mode::MessageBoxMode(x) => x.tick(ctx, world, pop_result),
             Mode::YesNoDialogMode(x) => x.tick(ctx, world, pop_result),
             Mode::AppQuitDialogMode(x) => x.tick(ctx, world, pop_result),
             Mode::InventoryActionMode(x) => x.tick(ctx, world, pop_result),
             Mode::EquipmentActionMode(x) => x.tick(ctx, world, pop_result),
+            Mode::SpellbookMode(x) => x.tick(ctx, world, pop_result),
         }
     }
 
@@ -159,11 +173,13 @@ impl Mode {
             Mode::GameOverMode(x) => x.draw(ctx, world, active),
             Mode::InventoryMode(x) => x.draw(ctx, world, active),
             Mode::TargetingMode(x) => x.draw(ctx, world, active),
+            Mode::LevelUpMode(x) => x.draw(ctx, world, active),
             Mode::MessageBoxMode(x) => x.draw(ctx, world, active),
             Mode::YesNoDialogMode(x) => x.draw(ctx, world, active),
             Mode::AppQuitDialogMode(x) => x.draw(ctx, world, active),
             Mode::InventoryActionMode(x) => x.draw(ctx, world, active),
             Mode::EquipmentActionMode(x) => x.draw(ctx, world, active),
+            Mode::SpellbookMode(x) => x.draw(ctx, world, active),
         }
     }
 
@@ -176,11 +192,13 @@ impl Mode {
             Mode::MainMenuMode(_) => false,
             Mode::InventoryMode(_) => true,
             Mode::TargetingMode(_) => false,
+            Mode::LevelUpMode(_) => true,
             Mode::MessageBoxMode(_) => true,
             Mode::YesNoDialogMode(_) => true,
             Mode::AppQuitDialogMode(_) => true,
             Mode::InventoryActionMode(_) => true,
             Mode::EquipmentActionMode(_) => true,
+            Mode::SpellbookMode(_) => true,
         }
     }
 }
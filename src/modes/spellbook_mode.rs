@@ -0,0 +1,155 @@
+use super::*;
+
+const TITLE: &str = "Spellbook";
+const NO_SPELLS: &str = "You don't know any spells.";
+
+#[derive(Debug)]
+pub enum SpellbookModeResult {
+    Cancelled,
+    Cast(Entity, Option<Point>),
+}
+
+#[derive(Debug)]
+pub struct SpellbookMode {
+    selection: usize,
+    /// Each known spell, its mana cost, and whether there's enough mana to cast it
+    spells: Vec<(Entity, String, i32, bool)>,
+    mana: (i32, i32),
+}
+
+/// Show the spells the player knows, and let them pick one to cast.
+impl SpellbookMode {
+    pub fn new(world: &World) -> Self {
+        let player = *world.fetch::<Entity>();
+        let mana =
+            world.read_storage::<ManaPool>().get(player).map_or((0, 0), |pool| (pool.current, pool.max));
+
+        let entities = world.entities();
+        let names = world.read_storage::<Name>();
+        let spells = (&entities, &world.read_storage::<Spell>(), &names)
+            .join()
+            .filter(|(_, spell, _)| spell.owner == player)
+            .map(|(e, spell, name)| (e, name.0.clone(), spell.mana_cost, spell.mana_cost <= mana.0))
+            .collect::<Vec<_>>();
+
+        Self { selection: 0, spells, mana }
+    }
+
+    fn confirm_spell(&self, ctx: &mut BTerm, world: &World) -> (ModeControl, ModeUpdate) {
+        let (spell, name, _, castable) = &self.spells[self.selection];
+
+        if !castable {
+            bo_logging::Logger::new().append("You don't have enough mana to cast").item_name(name).log();
+            return (ModeControl::Stay, ModeUpdate::Update);
+        }
+
+        if let Some(Ranged(range)) = world.read_storage::<Ranged>().get(*spell) {
+            return (
+                ModeControl::Push(TargetingMode::new(ctx, world, *spell, *range, true).into()),
+                ModeUpdate::Update,
+            );
+        }
+
+        (ModeControl::Pop(SpellbookModeResult::Cast(*spell, None).into()), ModeUpdate::Immediate)
+    }
+
+    pub fn tick(
+        &mut self,
+        ctx: &mut BTerm,
+        world: &mut World,
+        pop_result: &Option<ModeResult>,
+    ) -> (ModeControl, ModeUpdate) {
+        if let Some(result) = pop_result {
+            return match result {
+                ModeResult::TargetingModeResult(result) => match result {
+                    TargetingModeResult::Cancelled => (ModeControl::Stay, ModeUpdate::Update),
+                    TargetingModeResult::Target(spell, pt) => (
+                        ModeControl::Pop(SpellbookModeResult::Cast(*spell, Some(*pt)).into()),
+                        ModeUpdate::Immediate,
+                    ),
+                },
+                _ => (ModeControl::Stay, ModeUpdate::Update),
+            };
+        }
+
+        if let Some(key) = ctx.get_key() {
+            match key {
+                GameKey::Escape | GameKey::Cast => {
+                    return (ModeControl::Pop(SpellbookModeResult::Cancelled.into()), ModeUpdate::Immediate)
+                }
+                GameKey::Down if !self.spells.is_empty() => {
+                    self.selection = (self.selection + 1) % self.spells.len()
+                }
+                GameKey::Up if !self.spells.is_empty() => {
+                    self.selection = (self.selection + self.spells.len() - 1) % self.spells.len()
+                }
+                GameKey::Select if !self.spells.is_empty() => return self.confirm_spell(ctx, world),
+                _ => {}
+            }
+        }
+
+        (ModeControl::Stay, ModeUpdate::Update)
+    }
+
+    pub fn draw(&self, _ctx: &mut BTerm, _world: &mut World, _active: bool) {
+        let mut draw_batch = DrawBatch::new();
+        draw_batch.target(LAYER_TEXT);
+
+        let lines = self
+            .spells
+            .iter()
+            .map(|(_, name, cost, castable)| (format!("{} ({} mana)", name, cost), *castable))
+            .collect::<Vec<_>>();
+        let mana_line = format!("Mana: {}/{}", self.mana.0, self.mana.1);
+
+        let inner_width = 4 + TITLE
+            .len()
+            .max(NO_SPELLS.len())
+            .max(mana_line.len())
+            .max(lines.iter().map(|(line, _)| line.len()).max().unwrap_or(0))
+            as i32;
+
+        let box_rect = center_box(
+            &mut draw_batch,
+            (MAP_PANEL_WIDTH, MAP_PANEL_HEIGHT),
+            BoxConfig::new(
+                (inner_width, i32::max(1, lines.len() as i32) + 6),
+                ColorPair::new(WHITE, BLACK),
+                true,
+                false,
+            ),
+        );
+
+        let center_x = box_rect.x1 + box_rect.width() / 2 + 1;
+        let y = box_rect.y1 + 1;
+
+        draw_batch.print_color_centered_at(Point::new(center_x, y), TITLE, ColorPair::new(MAGENTA, BLACK));
+        draw_batch.print_color_centered_at(
+            Point::new(center_x, y + 1),
+            &mana_line,
+            ColorPair::new(CYAN, BLACK),
+        );
+
+        if lines.is_empty() {
+            draw_batch.print_color_centered_at(
+                Point::new(center_x, y + 3),
+                NO_SPELLS,
+                ColorPair::new(GRAY, BLACK),
+            );
+        }
+
+        // Spells there isn't enough mana for are greyed out
+        for (i, (line, castable)) in lines.iter().enumerate() {
+            let bg = if i == self.selection { crate::utils::SELECTED_BG } else { BLACK };
+            let fg = if *castable { WHITE } else { GRAY };
+
+            draw_batch.print_color_centered_at(
+                Point::new(center_x, y + 3 + i as i32),
+                line,
+                ColorPair::new(fg, bg),
+            );
+        }
+
+        draw_batch.submit(BATCH_UI_INV).expect("Batch error"); // On top of everything
+    }
+}
@@ -9,7 +9,7 @@ pub enum TargetingModeResult {
 
 #[derive(Debug)]
 pub struct TargetingMode {
-    radius: i32,
+    aoe: Option<AreaOfEffect>,
     item: Entity,
     warn_self: bool,
     item_name: String,
@@ -22,11 +22,10 @@ pub struct TargetingMode {
 /// Pick a target position within a certain range of the player.
 impl TargetingMode {
     pub fn new(ctx: &mut BTerm, world: &World, item: Entity, range: i32, warn_self: bool) -> Self {
-        let item_name = world.read_storage::<Name>().get(item).unwrap().0.clone();
-        let radius = world.read_storage::<AreaOfEffect>().get(item).map_or(0, |aoe| aoe.radius);
+        let item_name = get_item_display_name(world, item);
+        let aoe = world.read_storage::<AreaOfEffect>().get(item).copied();
 
         assert!(range >= 0);
-        assert!(radius >= 0);
 
         let player = world.fetch::<Entity>();
         let player_positon = *world.fetch::<Point>();
@@ -46,7 +45,7 @@ impl TargetingMode {
 
         Self {
             item,
-            radius,
+            aoe,
             warn_self,
             item_name,
             valid_cells,
@@ -56,13 +55,21 @@ impl TargetingMode {
         }
     }
 
-    fn should_warn(&self) -> bool {
+    /// Exactly the tiles the item would hit if aimed at `target`
+    fn affected_tiles(&self, map: &Map, target: Point) -> Vec<Point> {
+        match &self.aoe {
+            Some(aoe) => aoe_tiles(map, self.player_positon, target, aoe)
+                .into_iter()
+                .map(|idx| map.index_to_point2d(idx))
+                .collect(),
+            None => vec![target],
+        }
+    }
+
+    fn should_warn(&self, world: &World) -> bool {
         if self.warn_self {
             let map_mouse_pos = self.camera.screen_to_world(self.active_mouse_pt);
-            let distance = DistanceAlg::Pythagoras.distance2d(self.player_positon, map_mouse_pos);
-            if self.player_positon == map_mouse_pos || (self.radius > 0 && distance <= self.radius as f32) {
-                return true;
-            }
+            return self.affected_tiles(&world.fetch::<Map>(), map_mouse_pos).contains(&self.player_positon);
         }
 
         false
@@ -71,7 +78,7 @@ impl TargetingMode {
     pub fn tick(
         &mut self,
         ctx: &mut BTerm,
-        _world: &mut World,
+        world: &mut World,
         pop_result: &Option<ModeResult>,
     ) -> (ModeControl, ModeUpdate) {
         if let Some(result) = pop_result {
@@ -104,7 +111,7 @@ impl TargetingMode {
         if game_key == Some(GameKey::Select) || ctx.left_click {
             let map_mouse_pos = self.camera.screen_to_world(self.active_mouse_pt);
 
-            let result = if self.should_warn() {
+            let result = if self.should_warn(world) {
                 ModeControl::Push(
                     YesNoDialogMode::new(
                         format!(
@@ -149,19 +156,18 @@ impl TargetingMode {
             draw_batch.set_bg(screen_pt, BLUE);
         });
 
-        // Draw Blast Radius
+        // Draw the tiles that would be hit, as far as the player knows the map
         self.active_mouse_pt = if active { ctx.mouse_point() } else { self.active_mouse_pt };
         let mouse_map_pos = self.camera.screen_to_world(self.active_mouse_pt);
 
-        if self.radius > 0 {
+        if self.aoe.is_some() {
             let map = world.fetch::<Map>();
-            field_of_view_set(mouse_map_pos, self.radius, &*map)
-                .iter()
-                .filter(|pt| map.visible.get_bit(**pt))
-                .for_each(|pt| {
+            self.affected_tiles(&map, mouse_map_pos).iter().filter(|pt| map.revealed.get_bit(**pt)).for_each(
+                |pt| {
                     let screen_pt = self.camera.world_to_screen(*pt);
                     draw_batch.set_bg(screen_pt, LIGHT_RED);
-                });
+                },
+            );
         }
 
         // Draw Target Status
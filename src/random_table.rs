@@ -26,7 +26,7 @@ impl MasterTable {
 
     pub fn add<S: ToString>(&mut self, name: S, weight: i32, raws: &RawMaster) {
         match raws::spawn_type_by_name(raws, &name.to_string()) {
-            SpawnTableType::Mob => self.mobs.add(name, weight),
+            SpawnTableType::Mob | SpawnTableType::Group => self.mobs.add(name, weight),
             SpawnTableType::Item => self.items.add(name, weight),
             SpawnTableType::Prop => self.props.add(name, weight),
         }
@@ -66,7 +66,7 @@ impl RandomTable {
         let mut roll = crate::rng::roll_dice(1, self.total_weight) - 1;
         let mut index: usize = 0;
 
-        while roll > 0 {
+        while index < self.entries.len() {
             if roll < self.entries[index].weight {
                 return self.entries[index].name.clone();
             }
@@ -78,3 +78,19 @@ impl RandomTable {
         "None".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_entry_can_be_rolled_including_the_last() {
+        let mut table = RandomTable::new();
+        table.add("First", 1);
+        table.add("Last", 1);
+
+        let rolls: Vec<String> = (0..200).map(|_| table.roll()).collect();
+        assert!(rolls.iter().all(|name| name == "First" || name == "Last"));
+        assert!(rolls.iter().any(|name| name == "Last"));
+    }
+}
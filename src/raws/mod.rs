@@ -2,8 +2,10 @@ use bracket_lib::terminal::*;
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
 use ron::de::from_bytes;
+use serde::de::{DeserializeSeed, Deserializer, SeqAccess, Visitor};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::marker::PhantomData;
 
 mod rawmaster;
 mod templates;
@@ -11,10 +13,14 @@ mod templates;
 pub use rawmaster::*;
 pub use templates::*;
 
+embedded_resource!(RAW_FACTION_FILE, "../../resources/raws/factions.ron");
+embedded_resource!(RAW_HUNGER_FILE, "../../resources/raws/hunger.ron");
 embedded_resource!(RAW_ITEM_FILE, "../../resources/raws/items.ron");
+embedded_resource!(RAW_LOOT_TABLE_FILE, "../../resources/raws/loot_tables.ron");
 embedded_resource!(RAW_MOB_FILE, "../../resources/raws/mobs.ron");
 embedded_resource!(RAW_PROP_FILE, "../../resources/raws/props.ron");
 embedded_resource!(RAW_SPAWN_TABLE_FILE, "../../resources/raws/spawn_table.ron");
+embedded_resource!(RAW_SPELL_FILE, "../../resources/raws/spells.ron");
 
 lazy_static! {
     pub static ref RAWS: Mutex<RawMaster> = Mutex::new(RawMaster::empty());
@@ -25,29 +31,200 @@ pub struct Raws {
     pub mobs: Vec<RawMob>,
     pub props: Vec<RawProp>,
     pub items: Vec<RawItem>,
+    pub factions: Vec<RawFaction>,
     pub spawn_table: Vec<SpawnTableEntry>,
+    pub loot_tables: Vec<RawLootTable>,
+    pub spells: Vec<RawSpell>,
+    pub hunger: HungerConfig,
+}
+
+/// Reads a list of raws one entry at a time, counting how many made it through so that a failure
+/// can be pinned on the entry that caused it
+struct RawList<'a, T> {
+    parsed: &'a mut usize,
+    entries: PhantomData<T>,
+}
+
+impl<'de, 'a, T: Deserialize<'de>> DeserializeSeed<'de> for RawList<'a, T> {
+    type Value = Vec<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'a, T: Deserialize<'de>> Visitor<'de> for RawList<'a, T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a list of raws")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = seq.next_element()? {
+            entries.push(entry);
+            *self.parsed += 1;
+        }
+
+        Ok(entries)
+    }
 }
 
 impl Raws {
-    fn load_raw<'a, T: serde::Deserialize<'a>>(raw_data: &'static [u8]) -> T {
+    fn load_raw<'a, T: Deserialize<'a>>(raw_data: &'static [u8], file: &str) -> T {
         // Retrieve the raw data as an array of u8 (8-bit unsigned chars)
         match from_bytes::<T>(raw_data) {
             Ok(template) => template,
-            Err(e) => panic!("Unable to load template: {}", e),
+            Err(e) => panic!("Unable to load {}: {}", file, e),
+        }
+    }
+
+    fn load_raw_list<'a, T: Deserialize<'a>>(raw_data: &'static [u8], file: &str) -> Vec<T> {
+        let mut parsed = 0;
+        let result = ron::de::Deserializer::from_bytes(raw_data).and_then(|mut deserializer| {
+            let entries =
+                RawList { parsed: &mut parsed, entries: PhantomData }.deserialize(&mut deserializer)?;
+            deserializer.end()?;
+            Ok(entries)
+        });
+
+        match result {
+            Ok(entries) => entries,
+            Err(e) => match Raws::entry_name(raw_data, parsed) {
+                Some(name) => panic!("Unable to load {} (in \"{}\"): {}", file, name, e),
+                None => panic!("Unable to load {}: {}", file, e),
+            },
         }
     }
+
+    /// The name of the `index`th entry in a list of raws, if the file is at least well formed
+    fn entry_name(raw_data: &[u8], index: usize) -> Option<String> {
+        let entries = from_bytes::<Vec<ron::Value>>(raw_data).ok()?;
+        match entries.into_iter().nth(index)? {
+            ron::Value::Map(fields) => fields.iter().find_map(|(key, value)| match (key, value) {
+                (ron::Value::String(key), ron::Value::String(name)) if key == "name" => Some(name.clone()),
+                _ => None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Items, mobs, effects, loot tables and drops are checked once everything is loaded, since some
+    /// of them refer to other raws
+    fn validate(&self) {
+        if let Err(e) = self.hunger.validate() {
+            panic!("Unable to load resources/raws/hunger.ron: {}", e);
+        }
+
+        let check = |file: &str, name: &str, effects: &[RawEffect]| {
+            if let Err(e) = validate_effects(effects, &self.spells) {
+                panic!("Unable to load {} (in \"{}\"): {}", file, name, e);
+            }
+        };
+
+        for item in self.items.iter() {
+            if let Err(e) = item.validate() {
+                panic!("Unable to load resources/raws/items.ron (in \"{}\"): {}", item.name, e);
+            }
+
+            if let Some(consumable) = &item.consumable {
+                check("resources/raws/items.ron", &item.name, &consumable.effects);
+            }
+
+            if let Some(ammo) = item.weapon.as_ref().and_then(|weapon| weapon.ammo.as_ref()) {
+                if !self.has_ammunition(ammo) {
+                    panic!(
+                        "Unable to load resources/raws/items.ron (in \"{}\"): no item provides {} ammunition",
+                        item.name, ammo
+                    );
+                }
+            }
+        }
+
+        for prop in self.props.iter() {
+            if let Some(entry_trigger) = &prop.entry_trigger {
+                check("resources/raws/props.ron", &prop.name, &entry_trigger.effects);
+            }
+        }
+
+        for spell in self.spells.iter() {
+            check("resources/raws/spells.ron", &spell.name, &spell.effects);
+        }
+
+        for mob in self.mobs.iter() {
+            if let Err(e) = mob.validate() {
+                panic!("Unable to load resources/raws/mobs.ron (in \"{}\"): {}", mob.name, e);
+            }
+
+            if let Some(table) = mob.loot_table.as_ref().filter(|table| !self.has_loot_table(table)) {
+                panic!(
+                    "Unable to load resources/raws/mobs.ron (in \"{}\"): unknown loot table {}",
+                    mob.name, table
+                );
+            }
+
+            if let Some(faction) = mob.faction.as_ref().filter(|faction| !self.has_faction(faction)) {
+                panic!(
+                    "Unable to load resources/raws/mobs.ron (in \"{}\"): unknown faction {}",
+                    mob.name, faction
+                );
+            }
+        }
+
+        for loot in self.loot_tables.iter() {
+            for drop in loot.drops.iter() {
+                if !self.items.iter().any(|item| item.name == drop.name) {
+                    panic!(
+                        "Unable to load resources/raws/loot_tables.ron (in \"{}\"): unknown item {}",
+                        loot.name, drop.name
+                    );
+                }
+
+                if let Some(quantity) =
+                    drop.quantity.as_ref().filter(|quantity| crate::Dice::parse(quantity).is_none())
+                {
+                    panic!(
+                        "Unable to load resources/raws/loot_tables.ron (in \"{}\"): bad quantity {}",
+                        loot.name, quantity
+                    );
+                }
+            }
+        }
+    }
+
+    fn has_loot_table(&self, name: &str) -> bool { self.loot_tables.iter().any(|loot| loot.name == name) }
+
+    fn has_faction(&self, name: &str) -> bool { self.factions.iter().any(|faction| faction.name == name) }
+
+    fn has_ammunition(&self, kind: &str) -> bool {
+        self.items.iter().any(|item| item.ammunition.as_ref().is_some_and(|ammo| ammo.kind == kind))
+    }
 }
 
 pub fn load_raws() {
+    link_resource!(RAW_FACTION_FILE, "resources/raws/factions.ron");
+    link_resource!(RAW_HUNGER_FILE, "resources/raws/hunger.ron");
     link_resource!(RAW_ITEM_FILE, "resources/raws/items.ron");
+    link_resource!(RAW_LOOT_TABLE_FILE, "resources/raws/loot_tables.ron");
     link_resource!(RAW_MOB_FILE, "resources/raws/mobs.ron");
     link_resource!(RAW_PROP_FILE, "resources/raws/props.ron");
     link_resource!(RAW_SPAWN_TABLE_FILE, "resources/raws/spawn_table.ron");
+    link_resource!(RAW_SPELL_FILE, "resources/raws/spells.ron");
+
+    let mobs = Raws::load_raw_list::<RawMob>(RAW_MOB_FILE, "resources/raws/mobs.ron");
+    let items = Raws::load_raw_list::<RawItem>(RAW_ITEM_FILE, "resources/raws/items.ron");
+    let props = Raws::load_raw_list::<RawProp>(RAW_PROP_FILE, "resources/raws/props.ron");
+    let factions = Raws::load_raw_list::<RawFaction>(RAW_FACTION_FILE, "resources/raws/factions.ron");
+    let spawn_table =
+        Raws::load_raw_list::<SpawnTableEntry>(RAW_SPAWN_TABLE_FILE, "resources/raws/spawn_table.ron");
+    let loot_tables =
+        Raws::load_raw_list::<RawLootTable>(RAW_LOOT_TABLE_FILE, "resources/raws/loot_tables.ron");
+    let spells = Raws::load_raw_list::<RawSpell>(RAW_SPELL_FILE, "resources/raws/spells.ron");
+    let hunger = Raws::load_raw::<HungerConfig>(RAW_HUNGER_FILE, "resources/raws/hunger.ron");
 
-    let mobs = Raws::load_raw::<Vec<RawMob>>(RAW_MOB_FILE);
-    let items = Raws::load_raw::<Vec<RawItem>>(RAW_ITEM_FILE);
-    let props = Raws::load_raw::<Vec<RawProp>>(RAW_PROP_FILE);
-    let spawn_table = Raws::load_raw::<Vec<SpawnTableEntry>>(RAW_SPAWN_TABLE_FILE);
+    let raws = Raws { items, mobs, props, factions, spawn_table, loot_tables, spells, hunger };
+    raws.validate();
 
-    RAWS.lock().load(Raws { items, mobs, props, spawn_table });
+    RAWS.lock().load(raws);
 }
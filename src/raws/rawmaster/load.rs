@@ -14,6 +14,36 @@ impl RawMaster {
         load_entity_data(&self.raws.mobs, &mut self.mob_index, &mut used_names);
         // Props
         load_entity_data(&self.raws.props, &mut self.prop_index, &mut used_names);
+
+        // Groups
+        self.group_index = HashMap::new();
+        for (i, entry) in self.raws.spawn_table.iter().enumerate() {
+            if entry.group.is_some() {
+                if used_names.contains(&entry.name) {
+                    println!("WARNING - duplicate entity name in raws [{}]", entry.name);
+                }
+
+                self.group_index.insert(entry.name.clone(), i);
+            }
+        }
+
+        // Loot Tables
+        self.loot_index = HashMap::new();
+        for (i, loot) in self.raws.loot_tables.iter().enumerate() {
+            self.loot_index.insert(loot.name.clone(), i);
+        }
+
+        // Spells
+        self.spell_index = HashMap::new();
+        for (i, spell) in self.raws.spells.iter().enumerate() {
+            self.spell_index.insert(spell.name.clone(), i);
+        }
+
+        // Factions
+        self.faction_index = HashMap::new();
+        for faction in self.raws.factions.iter() {
+            self.faction_index.insert(faction.name.clone(), faction.responses.clone());
+        }
     }
 }
 
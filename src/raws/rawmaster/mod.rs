@@ -1,5 +1,5 @@
 use super::*;
-use crate::{ecs::*, MasterTable};
+use crate::{ecs::*, rng::Dice, MasterTable, RandomTable};
 use std::collections::HashMap;
 
 mod load;
@@ -15,6 +15,10 @@ pub struct RawMaster {
     mob_index: HashMap<String, usize>,
     item_index: HashMap<String, usize>,
     prop_index: HashMap<String, usize>,
+    group_index: HashMap<String, usize>,
+    loot_index: HashMap<String, usize>,
+    spell_index: HashMap<String, usize>,
+    faction_index: HashMap<String, HashMap<String, Reaction>>,
 }
 
 impl RawMaster {
@@ -23,11 +27,26 @@ impl RawMaster {
             mob_index: HashMap::new(),
             item_index: HashMap::new(),
             prop_index: HashMap::new(),
-            raws: Raws { items: Vec::new(), mobs: Vec::new(), props: Vec::new(), spawn_table: Vec::new() },
+            group_index: HashMap::new(),
+            loot_index: HashMap::new(),
+            spell_index: HashMap::new(),
+            faction_index: HashMap::new(),
+            raws: Raws {
+                items: Vec::new(),
+                mobs: Vec::new(),
+                props: Vec::new(),
+                factions: Vec::new(),
+                spawn_table: Vec::new(),
+                loot_tables: Vec::new(),
+                spells: Vec::new(),
+                hunger: HungerConfig::default(),
+            },
         }
     }
 }
 
+pub fn get_hunger_config(raws: &RawMaster) -> HungerConfig { raws.raws.hunger }
+
 pub fn get_spawn_table_for_depth(raws: &RawMaster, depth: i32) -> MasterTable {
     let available_options: Vec<&SpawnTableEntry> =
         raws.raws.spawn_table.iter().filter(|a| depth >= a.min_depth && depth <= a.max_depth).collect();
@@ -46,6 +65,58 @@ pub fn get_spawn_table_for_depth(raws: &RawMaster, depth: i32) -> MasterTable {
     rt
 }
 
+/// Rolls a drop table, returning the name and quantity of everything that drops
+pub fn get_loot_drops(raws: &RawMaster, table: &str) -> Vec<(String, i32)> {
+    let loot = match raws.loot_index.get(table) {
+        Some(idx) => &raws.raws.loot_tables[*idx],
+        None => return Vec::new(),
+    };
+
+    let mut rt = RandomTable::new();
+    loot.drops.iter().for_each(|drop| rt.add(&drop.name, drop.weight));
+
+    (0..loot.rolls.unwrap_or(1))
+        .filter_map(|_| {
+            let name = rt.roll();
+            let drop = loot.drops.iter().find(|drop| drop.name == name)?;
+            if crate::rng::roll_dice(1, 100) > drop.chance.unwrap_or(100) {
+                return None;
+            }
+
+            let quantity = drop.quantity.as_deref().and_then(Dice::parse).map_or(1, |dice| dice.roll());
+            Some((name, quantity)).filter(|(_, quantity)| *quantity > 0)
+        })
+        .collect()
+}
+
+/// Names of every item disguised as an unidentified scroll
+pub fn get_scroll_tags() -> Vec<String> { get_magic_tags(crate::SCROLL_NAMING) }
+
+/// Names of every item disguised as an unidentified potion
+pub fn get_potion_tags() -> Vec<String> { get_magic_tags(crate::POTION_NAMING) }
+
+fn get_magic_tags(naming: &str) -> Vec<String> {
+    RAWS.lock()
+        .raws
+        .items
+        .iter()
+        .filter(|item| item.magic.as_ref().is_some_and(|magic| magic.naming == naming))
+        .map(|item| item.name.clone())
+        .collect()
+}
+
+pub fn faction_reaction(my_faction: &str, their_faction: &str, raws: &RawMaster) -> Reaction {
+    if let Some(mf) = raws.faction_index.get(my_faction) {
+        if let Some(reaction) = mf.get(their_faction) {
+            return *reaction;
+        } else if let Some(default) = mf.get("Default") {
+            return *default;
+        }
+    }
+
+    Reaction::Neutral
+}
+
 pub fn get_renderable_component(glyph: &RawGlyph) -> crate::ecs::Glyph {
     let fg = RGB::from_hex(&glyph.fg).expect("Invalid RGB");
     let bg = RGB::from_hex(&glyph.bg).expect("Invalid RGB");
@@ -57,6 +128,12 @@ pub fn get_renderable_component(glyph: &RawGlyph) -> crate::ecs::Glyph {
     }
 }
 
+/// Slots are checked when the raws load, so anything that fails to parse here was never a raw
+fn parse_equipment_slot(tag: &str, slot: &str) -> EquipmentSlot {
+    EquipmentSlot::parse(slot)
+        .unwrap_or_else(|| unreachable!("Unchecked equipment slot for {}: {}", tag, slot))
+}
+
 fn find_slot_for_equippable_item(tag: &str, raws: &RawMaster) -> EquipmentSlot {
     if !raws.item_index.contains_key(tag) {
         panic!("Trying to equip an unknown item: {}", tag);
@@ -65,10 +142,12 @@ fn find_slot_for_equippable_item(tag: &str, raws: &RawMaster) -> EquipmentSlot {
     let item_index = raws.item_index[tag];
     let item = &raws.raws.items[item_index];
 
-    if let Some(_wpn) = &item.weapon {
-        return EquipmentSlot::Melee;
-    } else if let Some(_wearable) = &item.shield {
-        // return string_to_slot(&wearable.slot);
+    if let Some(wpn) = &item.weapon {
+        return if wpn.range.is_some() { EquipmentSlot::Ranged } else { EquipmentSlot::Melee };
+    } else if let Some(_shield) = &item.shield {
+        return EquipmentSlot::Shield;
+    } else if let Some(wearable) = &item.wearable {
+        return parse_equipment_slot(tag, &wearable.slot);
     }
 
     panic!("Trying to equip {}, but it has no slot tag.", tag);
@@ -1,22 +1,18 @@
 use super::*;
 use crate::{SpawnParticleBurst, SpawnParticleLine};
 
-pub fn parse_particle_line(n: &str) -> SpawnParticleLine {
-    let tokens: Vec<_> = n.split(';').collect();
-
+pub fn parse_particle_line(particle: &RawParticle) -> SpawnParticleLine {
     SpawnParticleLine::new(
-        to_cp437(tokens[0].chars().next().unwrap()),
-        RGB::from_hex(tokens[1]).expect("Bad RGB"),
-        tokens[2].parse::<f32>().unwrap(),
+        to_cp437(particle.glyph),
+        RGB::from_hex(&particle.color).expect("Bad RGB"),
+        particle.lifetime,
     )
 }
 
-pub fn parse_particle(n: &str) -> SpawnParticleBurst {
-    let tokens: Vec<_> = n.split(';').collect();
-
+pub fn parse_particle(particle: &RawParticle) -> SpawnParticleBurst {
     SpawnParticleBurst::new(
-        to_cp437(tokens[0].chars().next().unwrap()),
-        RGB::from_hex(tokens[1]).expect("Bad RGB"),
-        tokens[2].parse::<f32>().unwrap(),
+        to_cp437(particle.glyph),
+        RGB::from_hex(&particle.color).expect("Bad RGB"),
+        particle.lifetime,
     )
 }
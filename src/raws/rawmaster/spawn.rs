@@ -3,6 +3,17 @@
 
 use super::*;
 use crate::*;
+use std::collections::{HashSet, VecDeque};
+
+const DEFAULT_SEARCH_TURNS: i32 = 10;
+const DEFAULT_FACTION: &str = "Mindless";
+const DEFAULT_RANGED_DAMAGE: &str = "1d4";
+
+/// Percent chance, per level of depth, that equipment lying in the dungeon is cursed
+const CURSE_CHANCE_PER_DEPTH: i32 = 2;
+const MAX_CURSE_CHANCE: i32 = 25;
+/// Taken off the bonuses of equipment that was cursed at random
+const CURSE_PENALTY: i32 = 2;
 
 pub enum SpawnType {
     Carried(Entity),
@@ -54,68 +65,185 @@ pub fn spawn_base_entity<'a, T: raws::BaseRawComponent + Clone>(
     (eb, entity_template.clone())
 }
 
-macro_rules! apply_effects {
-    ( $effects:expr, $eb:expr ) => {
-        for effect in $effects.iter() {
-        let default = "".to_string();
-        let effect_name = effect.0.as_str();
-        let effect_options = effect.1.as_ref().unwrap_or(&default);
-            match effect_name {
-                AREA_OF_EFFECT => $eb = $eb.with(AreaOfEffect::new(effect_options.parse::<i32>().unwrap())),
-                // CONFUSION => {
-                //     $eb = $eb.with(Confusion{});
-                //     $eb = $eb.with(Duration{ turns: effect.1.unwrap().parse::<i32>().unwrap() });
-                // }
-                DAMAGE => $eb = $eb.with(InflictsDamage(effect_options.parse::<i32>().unwrap())),
-                // "damage_over_time" => $eb = $eb = $eb.with( DamageOverTime { damage : effect.1.unwrap().parse::<i32>().unwrap() } ),
-                // "duration" => $eb = $eb = $eb.with(Duration { turns: effect.1.unwrap().parse::<i32>().unwrap() }),
-                FOOD => $eb = $eb.with(ProvidesFood{}),
-                // "identify" => $eb = $eb = $eb.with(ProvidesIdentification{}),
-                MAGIC_MAPPING => $eb = $eb.with(MagicMapper{}),
-                PARTICLE => $eb = $eb.with(parse_particle(effect_options)),
-                PARTICLE_LINE => $eb = $eb.with(parse_particle_line(effect_options)),
-                PROVIDES_HEALING => $eb = $eb.with(ProvidesHealing(effect_options.parse::<i32>().unwrap())),
-                // "provides_mana" => $eb = $eb = $eb.with(ProvidesMana{ mana_amount: effect.1.unwrap().parse::<i32>().unwrap() }),
-                RANGED => $eb = $eb.with(Ranged(effect_options.parse::<i32>().unwrap())),
-                // "remove_curse" => $eb = $eb = $eb.with(ProvidesRemoveCurse{}),
-                SINGLE_ACTIVATION => $eb = $eb.with(SingleActivation{}),
-                // "slow" => $eb = $eb = $eb.with(Slow{ initiative_penalty : effect.1.unwrap().parse::<f32>().unwrap() }),
-                // "target_self" => $eb = $eb = $eb.with( AlwaysTargetsSelf{} ),
-                // "teach_spell" => $eb = $eb = $eb.with(TeachesSpell{ spell: effect.1.unwrap().to_string() }),
-                // "town_portal" => $eb = $eb = $eb.with(TownPortal{}),
-                _ => println!("Warning: consumable effect {} not implemented.", effect_name),
+/// Attaches the components for each effect, gathering any statuses into a single `InflictsStatus`.
+/// Effects are validated when the raws load, so the values here can be trusted.
+fn apply_effects<'a>(effects: &[RawEffect], mut eb: EntityBuilder<'a>) -> EntityBuilder<'a> {
+    let mut statuses: Vec<(StatusKind, i32)> = Vec::new();
+    let mut turns = DEFAULT_STATUS_DURATION;
+
+    for effect in effects.iter() {
+        match effect {
+            RawEffect::Ranged(range) => eb = eb.with(Ranged(*range)),
+            RawEffect::AreaOfEffect { radius, shape } => {
+                eb = eb.with(AreaOfEffect::new(shape.unwrap_or_default(), *radius))
             }
+            RawEffect::SingleActivation => eb = eb.with(SingleActivation {}),
+            RawEffect::Damage { amount, damage_type } => {
+                eb = eb.with(InflictsDamage::new(*amount, damage_type.unwrap_or_default()))
+            }
+            RawEffect::Healing(amount) => eb = eb.with(ProvidesHealing(*amount)),
+            RawEffect::Mana(amount) => eb = eb.with(ProvidesMana(*amount)),
+            RawEffect::Food(nutrition) => eb = eb.with(ProvidesFood(*nutrition)),
+            RawEffect::MagicMapping => eb = eb.with(MagicMapper {}),
+            RawEffect::Identify => eb = eb.with(ProvidesIdentification {}),
+            RawEffect::RemoveCurse => eb = eb.with(ProvidesRemoveCurse {}),
+            RawEffect::Recharge => eb = eb.with(ProvidesRecharge {}),
+            RawEffect::TeachSpell(spell) => eb = eb.with(TeachesSpell(spell.clone())),
+            RawEffect::Teleport => eb = eb.with(Teleports {}),
+            RawEffect::TeleportToTarget => eb = eb.with(TeleportsToTarget {}),
+            RawEffect::Blink(distance) => eb = eb.with(Blinks(*distance)),
+            RawEffect::Knockback(distance) => eb = eb.with(KnocksBack(*distance)),
+            RawEffect::Pull(distance) => eb = eb.with(Pulls(*distance)),
+            RawEffect::SwapPlaces => eb = eb.with(SwapsPlaces {}),
+            RawEffect::Hazard { kind, turns } => eb = eb.with(CreatesHazard::new(*kind, *turns)),
+            RawEffect::Douse => eb = eb.with(Douses {}),
+            RawEffect::Confusion => statuses.push((StatusKind::Confusion, 0)),
+            RawEffect::Blindness => statuses.push((StatusKind::Blindness, 0)),
+            RawEffect::Paralysis => statuses.push((StatusKind::Paralysis, 0)),
+            RawEffect::Poison(damage) => statuses.push((StatusKind::Poison, *damage)),
+            RawEffect::Regeneration(healing) => statuses.push((StatusKind::Regeneration, *healing)),
+            RawEffect::Haste(speed) => statuses.push((StatusKind::Haste, *speed)),
+            RawEffect::Slow(speed) => statuses.push((StatusKind::Slow, *speed)),
+            RawEffect::Duration(duration) => turns = *duration,
+            RawEffect::Particle(particle) => eb = eb.with(parse_particle(particle)),
+            RawEffect::ParticleLine(particle) => eb = eb.with(parse_particle_line(particle)),
         }
-    };
+    }
+
+    if !statuses.is_empty() {
+        eb = eb.with(InflictsStatus { statuses, turns });
+    }
+
+    eb
 }
 
 pub fn spawn_named_item(raws: &RawMaster, world: &mut World, key: &str, pos: SpawnType) -> Option<Entity> {
+    // Equipment found lying about has a chance of being cursed, which grows the deeper it's found
+    let random_curse = matches!(pos, SpawnType::AtPosition(_)) && {
+        let template = &raws.raws.items[raws.item_index[key]];
+        let depth = world.try_fetch::<Map>().map_or(0, |map| map.depth);
+        (template.weapon.is_some() || template.shield.is_some() || template.wearable.is_some())
+            && crate::rng::roll_dice(1, 100) <= i32::min(MAX_CURSE_CHANCE, depth * CURSE_CHANCE_PER_DEPTH)
+    };
+    let penalty = if random_curse { CURSE_PENALTY } else { 0 };
+
     let (mut eb, item_template) =
         spawn_base_entity(raws, world, &raws.raws.items, &raws.item_index, key, pos);
 
-    eb = eb.with(Item {});
+    eb = eb.with(Item::new(item_template.weight.unwrap_or(0.0), item_template.base_value.unwrap_or(0)));
 
     // Consumable
     if let Some(consumable) = &item_template.consumable {
-        eb = eb.with(Consumable {});
-        apply_effects!(consumable.effects, eb);
+        // Charged items stick around after use, where anything else is used up
+        match consumable.charges {
+            Some(charges) => {
+                eb = eb.with(Charges {
+                    remaining: charges,
+                    max: charges,
+                    crumbles: consumable.crumbles.unwrap_or(false),
+                })
+            }
+            None => eb = eb.with(Consumable {}),
+        }
+        eb = apply_effects(&consumable.effects, eb);
     }
 
-    // Weapon
-    if let Some(weapon) = &item_template.weapon {
+    // Ranged Weapon
+    if let Some(weapon) = item_template.weapon.as_ref().filter(|weapon| weapon.range.is_some()) {
+        // The power bonus goes straight onto the damage, since there's no melee roll to add it to
+        let mut damage = parse_damage_dice(key, weapon.damage.as_deref().unwrap_or(DEFAULT_RANGED_DAMAGE));
+        damage.bonus += weapon.power_bonus - penalty;
+
+        eb = eb.with(Equippable::new(EquipmentSlot::Ranged));
+        eb = eb.with(RangedWeapon {
+            range: weapon.range.unwrap_or(0),
+            damage,
+            damage_type: weapon.damage_type.unwrap_or_default(),
+            ammo: weapon.ammo.clone(),
+            thrown: weapon.thrown.unwrap_or(false),
+        });
+    }
+    // Melee Weapon
+    else if let Some(weapon) = &item_template.weapon {
         eb = eb.with(Equippable::new(EquipmentSlot::Melee));
-        eb = eb.with(MeleePowerBonus::new(weapon.power_bonus));
+        eb = eb.with(MeleePowerBonus::new(weapon.power_bonus - penalty));
+
+        if let Some(damage) = &weapon.damage {
+            eb = eb.with(MeleeDamage::new(
+                parse_damage_dice(key, damage),
+                weapon.damage_type.unwrap_or_default(),
+            ));
+        }
+
+        if let Some(attack_cost) = weapon.attack_cost {
+            eb = eb.with(AttackCost(attack_cost));
+        }
+
+        if weapon.two_handed.unwrap_or(false) {
+            eb = eb.with(TwoHanded {});
+        }
+    }
+    // Ammunition
+    if let Some(ammo) = &item_template.ammunition {
+        eb = eb.with(Ammunition {
+            kind: ammo.kind.clone(),
+            count: ammo.count,
+            recover_chance: ammo.recover_chance.unwrap_or(0),
+        });
     }
     // Shield
     if let Some(shield) = &item_template.shield {
         eb = eb.with(Equippable::new(EquipmentSlot::Shield));
-        eb = eb.with(DefenseBonus::new(shield.defense_bonus));
+        eb = eb.with(DefenseBonus::new(shield.defense_bonus - penalty));
+    }
+    // Armour and accessories
+    if let Some(wearable) = &item_template.wearable {
+        eb = eb.with(Equippable::new(parse_equipment_slot(key, &wearable.slot)));
+        eb = eb.with(DefenseBonus::new(wearable.defense_bonus - penalty));
+
+        if let Some(power_bonus) = wearable.power_bonus {
+            eb = eb.with(MeleePowerBonus::new(power_bonus - penalty));
+        }
+    }
+
+    if let Some(attributes) = &item_template.attributes {
+        eb = eb.with(AttributeBonus {
+            might: attributes.might,
+            fitness: attributes.fitness,
+            quickness: attributes.quickness,
+            intelligence: attributes.intelligence,
+        });
+    }
+
+    if let Some(resistances) = &item_template.resistances {
+        eb = eb.with(parse_resistances(resistances));
+    }
+
+    if random_curse || item_template.cursed.unwrap_or(false) {
+        eb = eb.with(Cursed { known: false });
     }
 
     Some(eb.build())
 }
 
+/// Gives `owner` the named spell, with the effects it has when cast attached just as they would
+/// be to an item
+pub fn spawn_named_spell(raws: &RawMaster, world: &mut World, key: &str, owner: Entity) -> Option<Entity> {
+    let spell_template = &raws.raws.spells[*raws.spell_index.get(key)?];
+
+    let mut eb = world
+        .create_entity()
+        .with(Name(spell_template.name.clone()))
+        .with(Spell::new(owner, spell_template.mana_cost))
+        .marked::<SimpleMarker<SerializeMe>>();
+
+    eb = apply_effects(&spell_template.effects, eb);
+
+    Some(eb.build())
+}
+
 pub fn spawn_named_mob(raws: &RawMaster, world: &mut World, key: &str, pos: SpawnType) -> Option<Entity> {
+    let home = if let SpawnType::AtPosition(pt) = &pos { *pt } else { Point::zero() };
     let (mut eb, mob_template) = spawn_base_entity(raws, world, &raws.raws.mobs, &raws.mob_index, key, pos);
 
     // AI Type
@@ -123,6 +251,16 @@ pub fn spawn_named_mob(raws: &RawMaster, world: &mut World, key: &str, pos: Spaw
         Some(ai_type) => match ai_type {
             AIType::Basic => {
                 eb = eb.with(Monster {});
+                eb = eb.with(MonsterAI::new(AIState::Idle, home, DEFAULT_SEARCH_TURNS, 0));
+            }
+            AIType::Monster(ai) => {
+                eb = eb.with(Monster {});
+                eb = eb.with(MonsterAI::new(
+                    ai.default_state,
+                    home,
+                    ai.search_turns.unwrap_or(DEFAULT_SEARCH_TURNS),
+                    ai.flee_below.unwrap_or(0),
+                ));
             }
             AIType::Bystander => {
                 eb = eb.with(Bystander {});
@@ -133,9 +271,13 @@ pub fn spawn_named_mob(raws: &RawMaster, world: &mut World, key: &str, pos: Spaw
         },
         None => {
             eb = eb.with(Monster {});
+            eb = eb.with(MonsterAI::new(AIState::Idle, home, DEFAULT_SEARCH_TURNS, 0));
         }
     }
 
+    // Faction
+    eb = eb.with(Faction::new(mob_template.faction.as_deref().unwrap_or(DEFAULT_FACTION)));
+
     if mob_template.blocks_tile {
         eb = eb.with(BlocksTile {});
     }
@@ -146,9 +288,93 @@ pub fn spawn_named_mob(raws: &RawMaster, world: &mut World, key: &str, pos: Spaw
         power: mob_template.stats.power,
         defense: mob_template.stats.defense,
     });
+    if let Some(damage) = &mob_template.stats.damage {
+        eb = eb.with(MeleeDamage::new(
+            parse_damage_dice(key, damage),
+            mob_template.stats.damage_type.unwrap_or_default(),
+        ));
+    }
+    if let Some(resistances) = &mob_template.resistances {
+        eb = eb.with(parse_resistances(resistances));
+    }
     eb = eb.with(FieldOfView::new(mob_template.vision_range));
+    let speed = mob_template.speed.unwrap_or(NORMAL_SPEED);
+    eb = eb.with(Initiative::new(speed));
 
-    Some(eb.build())
+    // Attributes
+    let attributes = mob_template.attributes.as_ref().map_or_else(Attributes::default, |attr| {
+        Attributes::new(
+            attr.might.unwrap_or(AVERAGE_ATTRIBUTE),
+            attr.fitness.unwrap_or(AVERAGE_ATTRIBUTE),
+            attr.quickness.unwrap_or(AVERAGE_ATTRIBUTE),
+            attr.intelligence.unwrap_or(AVERAGE_ATTRIBUTE),
+        )
+    });
+    eb = eb.with(attributes);
+    eb = eb.with(DerivedStats::new(mob_template.stats.max_hp, speed));
+    eb = eb.with(StatsChanged {});
+    eb = eb.with(Experience::new(mob_template.level.unwrap_or(1)));
+
+    // Remains
+    if let Some(loot_table) = &mob_template.loot_table {
+        eb = eb.with(LootTable(loot_table.clone()));
+    }
+    if let Some(corpse) = &mob_template.corpse {
+        eb = eb.with(LeavesCorpse {
+            edible: corpse.edible,
+            weight: corpse.weight,
+            nutrition: corpse.nutrition.unwrap_or(0),
+            poison: corpse.poison,
+        });
+    }
+
+    if let Some(ranged) = &mob_template.ranged {
+        eb = eb.with(RangedAttack::new(
+            ranged.range,
+            ranged.damage,
+            ranged.damage_type.unwrap_or_default(),
+            to_cp437(ranged.glyph.chars().next().unwrap()),
+            ranged.ammo,
+            ranged.cooldown.unwrap_or(0),
+        ));
+    }
+
+    let new_mob = eb.build();
+
+    // Starting gear
+    if let Some(equipped) = &mob_template.equipped {
+        for tag in equipped.iter() {
+            spawn_mob_item(raws, world, tag, SpawnType::Equipped(new_mob));
+        }
+    }
+    if let Some(carrying) = &mob_template.carrying {
+        for tag in carrying.iter() {
+            spawn_mob_item(raws, world, tag, SpawnType::Carried(new_mob));
+        }
+    }
+
+    Some(new_mob)
+}
+
+/// Damage dice are checked when the raws load, so anything that fails to parse here was never a raw
+fn parse_damage_dice(key: &str, dice: &str) -> Dice {
+    Dice::parse(dice).unwrap_or_else(|| unreachable!("Unchecked damage dice for {}: {}", key, dice))
+}
+
+fn parse_resistances(raw: &RawResistances) -> Resistances {
+    Resistances {
+        resist: raw.resist.clone().unwrap_or_default(),
+        immune: raw.immune.clone().unwrap_or_default(),
+        vulnerable: raw.vulnerable.clone().unwrap_or_default(),
+    }
+}
+
+fn spawn_mob_item(raws: &RawMaster, world: &mut World, tag: &str, pos: SpawnType) {
+    if raws.item_index.contains_key(tag) {
+        spawn_named_item(raws, world, tag, pos);
+    } else {
+        println!("WARNING: We don't know how to spawn [{}]!", tag);
+    }
 }
 
 #[rustfmt::skip]
@@ -174,15 +400,106 @@ pub fn spawn_named_prop(raws: &RawMaster, world: &mut World, key: &str, pos: Spa
     // Trigger Trait (Traps)
     if let Some(entry_trigger) = &prop_template.entry_trigger {
         eb = eb.with(EntryTrigger {});
-        apply_effects!(entry_trigger.effects, eb);
+        eb = apply_effects(&entry_trigger.effects, eb);
     }
 
     Some(eb.build())
 }
 
+/// Spawns a group's leader at `pos`, with its followers on the closest free tiles around it
+pub fn spawn_named_group(raws: &RawMaster, world: &mut World, key: &str, pos: SpawnType) -> Option<Entity> {
+    let group = raws.raws.spawn_table[raws.group_index[key]].group.as_ref()?;
+    let start = if let SpawnType::AtPosition(pt) = pos { pt } else { return None };
+
+    let leader = spawn_named_mob(raws, world, &group.leader, SpawnType::AtPosition(start))?;
+    let followers = roll_group_members(group);
+
+    let tiles = {
+        let map = world.fetch::<Map>();
+        let occupied: HashSet<usize> = (&world.read_storage::<Point>(), &world.read_storage::<BlocksTile>())
+            .join()
+            .map(|(pt, _)| map.point2d_to_index(*pt))
+            .chain(std::iter::once(map.point2d_to_index(start)))
+            .collect();
+
+        find_free_tiles_near(&map, start, followers.len(), |idx| {
+            occupied.contains(&idx) || crate::spatial::is_blocked(idx)
+        })
+    };
+
+    for (name, pt) in followers.iter().zip(tiles) {
+        if let Some(follower) = spawn_named_mob(raws, world, name, SpawnType::AtPosition(pt)) {
+            world
+                .write_storage::<Follower>()
+                .insert(follower, Follower::new(leader))
+                .expect("Unable to insert follower");
+        }
+    }
+
+    Some(leader)
+}
+
+/// The spawn-table group called `key`, if there is one
+pub fn get_spawn_group(raws: &RawMaster, key: &str) -> Option<RawSpawnGroup> {
+    raws.group_index.get(key).and_then(|idx| raws.raws.spawn_table[*idx].group.clone())
+}
+
+/// Rolls how many of each member turn up, giving the name of every follower
+pub fn roll_group_members(group: &RawSpawnGroup) -> Vec<String> {
+    let mut followers = Vec::new();
+    for member in group.members.iter() {
+        for _ in 0..crate::rng::range(member.min, member.max + 1) {
+            followers.push(member.name.clone());
+        }
+    }
+
+    followers
+}
+
+/// Breadth-first search outward from `start` for up to `count` walkable tiles that aren't `taken`
+pub fn find_free_tiles_near<F>(map: &Map, start: Point, count: usize, taken: F) -> Vec<Point>
+where
+    F: Fn(usize) -> bool,
+{
+    const MAX_GROUP_SPREAD: i32 = 4;
+
+    let mut found = Vec::new();
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut open_list = VecDeque::from([start]);
+    visited.insert(start);
+
+    while let Some(pt) = open_list.pop_front() {
+        if found.len() >= count {
+            break;
+        }
+
+        if !taken(map.point2d_to_index(pt)) {
+            found.push(pt);
+        }
+
+        for delta in [Point::new(-1, 0), Point::new(1, 0), Point::new(0, -1), Point::new(0, 1)] {
+            let next = pt + delta;
+            if !map.in_bounds(next)
+                || visited.contains(&next)
+                || DistanceAlg::Chebyshev.distance2d(start, next) > MAX_GROUP_SPREAD as f32
+                || !map.tiles[map.point2d_to_index(next)].walkable
+            {
+                continue;
+            }
+
+            visited.insert(next);
+            open_list.push_back(next);
+        }
+    }
+
+    found
+}
+
 pub fn spawn_named_entity(world: &mut World, key: &str, pos: SpawnType) -> Option<Entity> {
     let raws = RAWS.lock();
-    if raws.item_index.contains_key(key) {
+    if raws.group_index.contains_key(key) {
+        return spawn_named_group(&raws, world, key, pos);
+    } else if raws.item_index.contains_key(key) {
         return spawn_named_item(&raws, world, key, pos);
     } else if raws.mob_index.contains_key(key) {
         return spawn_named_mob(&raws, world, key, pos);
@@ -197,10 +514,13 @@ pub enum SpawnTableType {
     Item,
     Mob,
     Prop,
+    Group,
 }
 
 pub fn spawn_type_by_name(raws: &RawMaster, key: &str) -> SpawnTableType {
-    if raws.item_index.contains_key(key) {
+    if raws.group_index.contains_key(key) {
+        SpawnTableType::Group
+    } else if raws.item_index.contains_key(key) {
         SpawnTableType::Item
     } else if raws.mob_index.contains_key(key) {
         SpawnTableType::Mob
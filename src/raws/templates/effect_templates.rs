@@ -0,0 +1,140 @@
+use super::*;
+use crate::ecs::{AoeShape, DamageType};
+use crate::map::HazardKind;
+
+/// One thing a consumable, trap or spell does when it goes off, written like `Ranged(6)` or
+/// `Damage(amount: 8, damage_type: Some(Fire))`.
+#[derive(Deserialize, Debug, Clone)]
+pub enum RawEffect {
+    /// Needs aiming, up to this many tiles away
+    Ranged(i32),
+    /// Hits everything in a shape around the target, a circle unless told otherwise
+    AreaOfEffect {
+        radius: i32,
+        shape: Option<AoeShape>,
+    },
+    /// A trap that is gone once it has gone off
+    SingleActivation,
+    /// Damage with no type given is physical
+    Damage {
+        amount: i32,
+        damage_type: Option<DamageType>,
+    },
+    Healing(i32),
+    Mana(i32),
+    /// Nutrition provided
+    Food(i32),
+    MagicMapping,
+    Identify,
+    RemoveCurse,
+    Recharge,
+    /// Names the spell learned
+    TeachSpell(String),
+    /// Sends the target somewhere random on the map
+    Teleport,
+    /// Moves the user to the tile it was aimed at, so it needs `Ranged`
+    TeleportToTarget,
+    /// Sends the target somewhere random, no more than this many tiles away
+    Blink(i32),
+    /// Tiles the target is shoved away from the user
+    Knockback(i32),
+    /// Tiles the target is dragged towards the user
+    Pull(i32),
+    SwapPlaces,
+    /// Leaves fire, gas and the like behind for this many turns
+    Hazard {
+        kind: HazardKind,
+        turns: i32,
+    },
+    /// Puts out fires
+    Douse,
+    Confusion,
+    Blindness,
+    Paralysis,
+    /// Damage per turn
+    Poison(i32),
+    /// Healing per turn
+    Regeneration(i32),
+    /// Speed gained
+    Haste(i32),
+    /// Speed lost
+    Slow(i32),
+    /// How many turns every status on the effect lasts
+    Duration(i32),
+    Particle(RawParticle),
+    ParticleLine(RawParticle),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawParticle {
+    pub glyph: char,
+    pub color: String,
+    /// In ms
+    pub lifetime: f32,
+}
+
+impl RawEffect {
+    pub fn is_status(&self) -> bool {
+        matches!(
+            self,
+            RawEffect::Confusion
+                | RawEffect::Blindness
+                | RawEffect::Paralysis
+                | RawEffect::Poison(_)
+                | RawEffect::Regeneration(_)
+                | RawEffect::Haste(_)
+                | RawEffect::Slow(_)
+        )
+    }
+
+    /// Catches the values the file format can't, such as damage types and spells that don't exist
+    pub fn validate(&self, spells: &[RawSpell]) -> Result<(), String> {
+        match self {
+            RawEffect::Ranged(range) | RawEffect::AreaOfEffect { radius: range, .. } if *range < 1 => {
+                Err(format!("{:?} needs a range of at least 1", self))
+            }
+            RawEffect::TeachSpell(name) if !spells.iter().any(|spell| spell.name == *name) => {
+                Err(format!("teaches unknown spell \"{}\"", name))
+            }
+            RawEffect::Blink(distance) | RawEffect::Knockback(distance) | RawEffect::Pull(distance)
+                if *distance < 1 =>
+            {
+                Err(format!("{:?} needs a distance of at least 1", self))
+            }
+            RawEffect::Duration(turns) | RawEffect::Hazard { turns, .. } if *turns < 1 => {
+                Err(format!("{:?} needs at least 1 turn", self))
+            }
+            RawEffect::Particle(particle) | RawEffect::ParticleLine(particle)
+                if RGB::from_hex(&particle.color).is_err() =>
+            {
+                Err(format!("bad particle color \"{}\"", particle.color))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks a whole effect list, including that a duration has statuses to time and that no kind of
+/// effect is given twice
+pub fn validate_effects(effects: &[RawEffect], spells: &[RawSpell]) -> Result<(), String> {
+    for (i, effect) in effects.iter().enumerate() {
+        effect.validate(spells)?;
+
+        let kind = std::mem::discriminant(effect);
+        if effects[..i].iter().any(|earlier| std::mem::discriminant(earlier) == kind) {
+            return Err(format!("{:?} given more than once", effect));
+        }
+    }
+
+    let has_duration = effects.iter().any(|effect| matches!(effect, RawEffect::Duration(_)));
+    if has_duration && !effects.iter().any(|effect| effect.is_status()) {
+        return Err("Duration given without any status to last".to_string());
+    }
+
+    let teleports_to_target = effects.iter().any(|effect| matches!(effect, RawEffect::TeleportToTarget));
+    if teleports_to_target && !effects.iter().any(|effect| matches!(effect, RawEffect::Ranged(_))) {
+        return Err("TeleportToTarget given without a Ranged target".to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+#[derive(Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Reaction {
+    Hostile,
+    Neutral,
+    Friendly,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawFaction {
+    pub name: String,
+    pub responses: HashMap<String, Reaction>,
+}
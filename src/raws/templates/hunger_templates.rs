@@ -0,0 +1,56 @@
+use super::*;
+use crate::ecs::HungerState;
+
+/// How quickly eaters get hungry. Nutrition drains by `consumption` each turn an eater acts,
+/// scaled by that eater's own clock rate, and the thresholds below decide how hungry they feel.
+#[derive(Deserialize, Debug, Default, Clone, Copy)]
+pub struct HungerConfig {
+    pub max_nutrition: i32,
+    pub starting_nutrition: i32,
+    /// At or above this, the eater is well fed
+    pub well_fed: i32,
+    /// Below this, the eater is hungry
+    pub hungry: i32,
+    /// At or below this, the eater is starving
+    pub starving: i32,
+    pub consumption: i32,
+    /// Percentage of `consumption` the player burns through
+    pub player_rate: i32,
+    /// Taken every turn spent starving
+    pub starvation_damage: i32,
+}
+
+impl HungerConfig {
+    /// The thresholds have to climb in order, or an eater could be hungry and well fed at once
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.starving < self.hungry
+            && self.hungry <= self.well_fed
+            && self.well_fed <= self.max_nutrition)
+        {
+            return Err(format!(
+                "thresholds must climb: starving ({}) < hungry ({}) <= well_fed ({}) <= max_nutrition ({})",
+                self.starving, self.hungry, self.well_fed, self.max_nutrition
+            ));
+        }
+        if self.consumption < 1 {
+            return Err(format!("consumption must be at least 1 ({})", self.consumption));
+        }
+
+        Ok(())
+    }
+
+    pub fn state_for(&self, nutrition: i32) -> HungerState {
+        if nutrition >= self.well_fed {
+            HungerState::WellFed
+        } else if nutrition <= self.starving {
+            HungerState::Starving
+        } else if nutrition < self.hungry {
+            HungerState::Hungry
+        } else {
+            HungerState::Normal
+        }
+    }
+
+    /// How much nutrition a clock running at `rate` percent loses in a turn
+    pub fn consumption_at(&self, rate: i32) -> i32 { i32::max(1, self.consumption * rate / 100) }
+}
@@ -1,16 +1,54 @@
 use super::*;
-use crate::{impl_raw, RenderOrder};
+use crate::{impl_raw, DamageType, EquipmentSlot, RenderOrder, POTION_NAMING, SCROLL_NAMING};
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawItem {
     pub name: String,
     pub glyph: Option<RawGlyph>,
+    /// In pounds
+    pub weight: Option<f32>,
+    pub base_value: Option<i32>,
     pub weapon: Option<RawWeapon>,
     pub shield: Option<RawShield>,
+    pub wearable: Option<RawWearable>,
+    pub ammunition: Option<RawAmmunition>,
     pub consumable: Option<RawConsumable>,
+    pub attributes: Option<RawAttributes>,
+    pub resistances: Option<RawResistances>,
+    pub magic: Option<RawMagicItem>,
+    /// Cursed from the start, rather than only by chance
+    pub cursed: Option<bool>,
 }
 impl_raw!(RawItem);
 
+impl RawItem {
+    /// Catches the damage dice, slots, charges and namings the file format can't
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(weapon) = &self.weapon {
+            validate_dice(weapon.damage.as_deref())?;
+        }
+
+        if let Some(wearable) =
+            self.wearable.as_ref().filter(|wearable| EquipmentSlot::parse(&wearable.slot).is_none())
+        {
+            return Err(format!("unknown equipment slot \"{}\"", wearable.slot));
+        }
+
+        if let Some(charges) =
+            self.consumable.as_ref().and_then(|consumable| consumable.charges).filter(|charges| *charges < 1)
+        {
+            return Err(format!("charges must be at least 1 ({})", charges));
+        }
+
+        let known_naming = |naming: &str| naming == SCROLL_NAMING || naming == POTION_NAMING;
+        if let Some(magic) = self.magic.as_ref().filter(|magic| !known_naming(&magic.naming)) {
+            return Err(format!("unknown magic naming \"{}\"", magic.naming));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawGlyph {
     pub fg: String,
@@ -19,18 +57,51 @@ pub struct RawGlyph {
     pub order: RenderOrder,
 }
 
+/// An item whose true name stays hidden until it is used or identified
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawMagicItem {
+    /// How it is disguised in the meantime: `"scroll"` or `"potion"`
+    pub naming: String,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawConsumable {
-    pub effects: HashMap<String, Option<String>>,
+    pub effects: Vec<RawEffect>,
+    /// Uses before the item is spent. Without charges, the item is used up in one go.
+    pub charges: Option<i32>,
+    /// Whether a spent item crumbles away, rather than waiting to be recharged
+    pub crumbles: Option<bool>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawWeapon {
     pub power_bonus: i32,
+    pub damage: Option<String>,
+    pub damage_type: Option<DamageType>,
     pub range: Option<i32>,
+    pub attack_cost: Option<i32>,
+    pub two_handed: Option<bool>,
+    /// Kind of ammunition a ranged weapon fires
+    pub ammo: Option<String>,
+    pub thrown: Option<bool>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawAmmunition {
+    pub kind: String,
+    pub count: i32,
+    pub recover_chance: Option<i32>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawShield {
     pub defense_bonus: i32,
 }
+
+/// Armour and accessories, worn in the named slot
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawWearable {
+    pub slot: String,
+    pub defense_bonus: i32,
+    pub power_bonus: Option<i32>,
+}
@@ -0,0 +1,20 @@
+use super::*;
+
+/// A mob's drop table. Each roll picks one entry by weight, which then drops `chance` percent of
+/// the time.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawLootTable {
+    pub name: String,
+    pub rolls: Option<i32>,
+    pub drops: Vec<RawLootDrop>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawLootDrop {
+    pub name: String,
+    pub weight: i32,
+    /// Percent chance the entry drops once picked (default 100)
+    pub chance: Option<i32>,
+    /// Dice rolled for how many drop (default 1)
+    pub quantity: Option<String>,
+}
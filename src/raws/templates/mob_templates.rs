@@ -1,11 +1,19 @@
 use super::*;
-use crate::impl_raw;
+use crate::{impl_raw, AIState, DamageType, Dice};
 
 #[derive(Deserialize, Debug, Clone)]
 pub enum AIType {
     Basic,
     Vendor,
     Bystander,
+    Monster(RawMonsterAI),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawMonsterAI {
+    pub default_state: AIState,
+    pub flee_below: Option<i32>,
+    pub search_turns: Option<i32>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -13,16 +21,106 @@ pub struct RawMob {
     pub name: String,
     pub blocks_tile: bool,
     pub vision_range: i32,
+    pub level: Option<i32>,
+    pub speed: Option<i32>,
     pub ai: Option<AIType>,
+    pub faction: Option<String>,
     pub stats: RawMobStats,
+    pub attributes: Option<RawAttributes>,
+    pub resistances: Option<RawResistances>,
     pub glyph: Option<RawGlyph>,
+    pub ranged: Option<RawRangedAttack>,
+    pub equipped: Option<Vec<String>>,
+    pub carrying: Option<Vec<String>>,
+    pub loot_table: Option<String>,
+    pub corpse: Option<RawCorpse>,
 }
 impl_raw!(RawMob);
 
+impl RawMob {
+    /// Catches the values the file format can't, so that a bad mob fails when the raws load rather
+    /// than when it first spawns
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(speed) = self.speed.filter(|speed| *speed < 1) {
+            return Err(format!("speed must be at least 1 ({})", speed));
+        }
+
+        validate_dice(self.stats.damage.as_deref())?;
+
+        if let Some(ranged) = &self.ranged {
+            if ranged.glyph.is_empty() {
+                return Err("ranged attack has no glyph".to_string());
+            }
+        }
+
+        if let Some(AIType::Monster(ai)) = &self.ai {
+            if let Some(turns) = ai.search_turns.filter(|turns| *turns < 0) {
+                return Err(format!("search_turns can't be negative ({})", turns));
+            }
+            if let Some(percent) = ai.flee_below.filter(|percent| *percent < 0) {
+                return Err(format!("flee_below can't be negative ({})", percent));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawMobStats {
     pub hp: i32,
     pub power: i32,
     pub max_hp: i32,
     pub defense: i32,
+    pub damage: Option<String>,
+    pub damage_type: Option<DamageType>,
+}
+
+/// Attribute scores for mobs, or attribute changes for equipment. Missing entries are average
+/// (or unchanged).
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawAttributes {
+    pub might: Option<i32>,
+    pub fitness: Option<i32>,
+    pub quickness: Option<i32>,
+    pub intelligence: Option<i32>,
+}
+
+/// Damage types a mob (or whoever wears a piece of armour) takes half, no or double damage from
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawResistances {
+    pub resist: Option<Vec<DamageType>>,
+    pub immune: Option<Vec<DamageType>>,
+    pub vulnerable: Option<Vec<DamageType>>,
+}
+
+/// Checks damage dice, if any were given. Damage types need no check, since the file format
+/// only accepts known ones.
+pub fn validate_dice(dice: Option<&str>) -> Result<(), String> {
+    if let Some(dice) = dice.filter(|dice| Dice::parse(dice).is_none()) {
+        return Err(format!("invalid damage dice \"{}\"", dice));
+    }
+
+    Ok(())
+}
+
+/// What a mob leaves behind when it dies
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawCorpse {
+    pub edible: bool,
+    /// In pounds
+    pub weight: f32,
+    pub nutrition: Option<i32>,
+    /// Bad meat poisons whoever eats it, this badly
+    pub poison: Option<i32>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawRangedAttack {
+    pub range: i32,
+    pub damage: i32,
+    pub damage_type: Option<DamageType>,
+    pub glyph: String,
+    pub ammo: Option<i32>,
+    pub cooldown: Option<i32>,
 }
@@ -2,15 +2,25 @@ use super::*;
 use core::fmt::Debug;
 use std::any::Any;
 
+mod effect_templates;
+mod faction_templates;
+mod hunger_templates;
 mod item_templates;
+mod loot_templates;
 mod mob_templates;
 mod prop_templates;
 mod spawn_table_templates;
+mod spell_templates;
 
+pub use effect_templates::*;
+pub use faction_templates::*;
+pub use hunger_templates::*;
 pub use item_templates::*;
+pub use loot_templates::*;
 pub use mob_templates::*;
 pub use prop_templates::*;
 pub use spawn_table_templates::*;
+pub use spell_templates::*;
 
 pub trait BaseRawComponent: Debug + Clone {
     fn name(&self) -> String;
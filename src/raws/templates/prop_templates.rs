@@ -1,7 +1,6 @@
 use crate::impl_raw;
 
 use super::*;
-use std::collections::HashMap;
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawProp {
@@ -18,5 +17,5 @@ impl_raw!(RawProp);
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct RawEntryTrigger {
-    pub effects: HashMap<String, Option<String>>,
+    pub effects: Vec<RawEffect>,
 }
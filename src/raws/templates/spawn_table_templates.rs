@@ -7,6 +7,20 @@ pub struct SpawnTableEntry {
     pub min_depth: i32,
     pub max_depth: i32,
     pub add_map_depth_to_weight: Option<bool>,
+    pub group: Option<RawSpawnGroup>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawSpawnGroup {
+    pub leader: String,
+    pub members: Vec<RawGroupMember>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawGroupMember {
+    pub name: String,
+    pub min: i32,
+    pub max: i32,
 }
 
 #[derive(Deserialize, Debug, Clone)]
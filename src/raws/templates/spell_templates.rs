@@ -0,0 +1,9 @@
+use super::*;
+
+/// A spell that can be learned and cast. Its effects are the same as a consumable item's.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RawSpell {
+    pub name: String,
+    pub mana_cost: i32,
+    pub effects: Vec<RawEffect>,
+}
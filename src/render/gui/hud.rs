@@ -86,8 +86,26 @@ pub fn draw_stats(world: &World, draw_batch: &mut DrawBatch, player_entity: &Ent
     let player_stats = stats.get(*player_entity).unwrap();
 
     let health = format!("Health: {}/{}", player_stats.hp, player_stats.max_hp);
-    let mana = format!("Mana:   {}/{}", 30, 30);
-    let xp = format!("Level:  {}", 1);
+    let mana_pools = world.read_storage::<ManaPool>();
+    let (current_mana, max_mana) =
+        mana_pools.get(*player_entity).map_or((0, 0), |pool| (pool.current, pool.max));
+    let mana = format!("Mana:   {}/{}", current_mana, max_mana);
+    let experience = world.read_storage::<Experience>();
+    // A save or spawn path that left experience out shouldn't take the whole HUD down with it
+    let player_xp = experience.get(*player_entity).cloned().unwrap_or_else(|| Experience::new(1));
+    let xp = format!("Level:  {}", player_xp.level);
+    let hunger = world.fetch::<HungerConfig>();
+    let hunger_clocks = world.read_storage::<HungerClock>();
+    let (nutrition, hunger_color) = hunger_clocks.get(*player_entity).map_or((0, WHITE), |hc| {
+        let color = match hc.state {
+            HungerState::WellFed => GREEN,
+            HungerState::Normal => SANDYBROWN,
+            HungerState::Hungry => ORANGE,
+            HungerState::Starving => RED,
+        };
+        (hc.nutrition, color)
+    });
+    let food = format!("Food:   {}%", nutrition * 100 / i32::max(1, hunger.max_nutrition));
 
     let text_x = STATS_PANEL.x1 + 1;
     let bar_x = text_x + 14;
@@ -95,6 +113,7 @@ pub fn draw_stats(world: &World, draw_batch: &mut DrawBatch, player_entity: &Ent
     draw_batch.print_color(Point::new(text_x, 1), &health, ColorPair::new(WHITE, BLACK));
     draw_batch.print_color(Point::new(text_x, 2), &mana, ColorPair::new(WHITE, BLACK));
     draw_batch.print_color(Point::new(text_x, 3), &xp, ColorPair::new(WHITE, BLACK));
+    draw_batch.print_color(Point::new(text_x, 4), &food, ColorPair::new(WHITE, BLACK));
 
     draw_batch.bar_horizontal(
         Point::new(bar_x, 1),
@@ -103,15 +122,59 @@ pub fn draw_stats(world: &World, draw_batch: &mut DrawBatch, player_entity: &Ent
         player_stats.max_hp,
         ColorPair::new(RED, BLACK),
     );
-    draw_batch.bar_horizontal(Point::new(bar_x, 2), 14, 20, 30, ColorPair::new(NAVYBLUE, BLACK));
-    let xp_level_start = 0;
+    draw_batch.bar_horizontal(
+        Point::new(bar_x, 2),
+        14,
+        current_mana,
+        max_mana,
+        ColorPair::new(NAVYBLUE, BLACK),
+    );
+    let xp_level_start = player_xp.level_start_xp();
     draw_batch.bar_horizontal(
         Point::new(bar_x, 3),
         14,
-        0 - xp_level_start,
-        1000,
+        player_xp.xp - xp_level_start,
+        player_xp.next_level_xp() - xp_level_start,
         ColorPair::new(GOLD, BLACK),
     );
+    draw_batch.bar_horizontal(
+        Point::new(bar_x, 4),
+        14,
+        nutrition,
+        hunger.max_nutrition,
+        ColorPair::new(hunger_color, BLACK),
+    );
+}
+
+fn equipment(ecs: &World, draw_batch: &mut DrawBatch, player_entity: &Entity) {
+    let dm = ecs.fetch::<MasterDungeonMap>();
+    let names = ecs.read_storage::<Name>();
+    let equipped = ecs.read_storage::<Equipped>();
+    let cursed = ecs.read_storage::<Cursed>();
+    let worn = (&ecs.entities(), &names, &equipped)
+        .join()
+        .filter(|(_, _, equip)| equip.owner == *player_entity)
+        .collect::<Vec<_>>();
+
+    let x = EQUIPMENT_PANEL.x1 + 1;
+    let mut y = EQUIPMENT_PANEL.y1 + 1;
+    for slot in EquipmentSlot::ALL.iter() {
+        if let Some((item, name, _)) = worn.iter().find(|(_, _, equip)| equip.slot == *slot) {
+            draw_batch.print_color(
+                Point::new(x, y),
+                format!("{}:", slot.name()),
+                ColorPair::new(GRAY, BLACK),
+            );
+            // Known curses show up in red
+            let color = if cursed.get(*item).is_some_and(|curse| curse.known) { RED } else { WHITE };
+            draw_batch.print_color(
+                Point::new(x + 8, y),
+                dm.display_name(&name.0),
+                ColorPair::new(color, BLACK),
+            );
+            y += 1;
+        }
+    }
 }
 
 fn status(ecs: &World, draw_batch: &mut DrawBatch, player_entity: &Entity) {
@@ -119,20 +182,41 @@ fn status(ecs: &World, draw_batch: &mut DrawBatch, player_entity: &Entity) {
     let hc = hunger.get(*player_entity).unwrap();
 
     let x = EQUIPMENT_PANEL.x1 + 1;
-    let y = EQUIPMENT_PANEL.y2 - 1;
+    let mut y = EQUIPMENT_PANEL.y2 - 1;
     match hc.state {
         HungerState::Normal => {}
         HungerState::WellFed => {
             draw_batch.print_color(Point::new(x, y), "Well Fed", ColorPair::new(GREEN, BLACK));
-            // y -= 1;
+            y -= 1;
         }
         HungerState::Hungry => {
             draw_batch.print_color(Point::new(x, y), "Hungry", ColorPair::new(ORANGE, BLACK));
-            // y -= 1;
+            y -= 1;
         }
         HungerState::Starving => {
             draw_batch.print_color(Point::new(x, y), "Starving", ColorPair::new(RED, BLACK));
-            // y -= 1;
+            y -= 1;
+        }
+    }
+
+    if let Some(derived) = ecs.read_storage::<DerivedStats>().get(*player_entity) {
+        if derived.encumbrance != Encumbrance::Unburdened {
+            let color = if derived.encumbrance == Encumbrance::Burdened { ORANGE } else { RED };
+            draw_batch.print_color(
+                Point::new(x, y),
+                derived.encumbrance.name(),
+                ColorPair::new(color, BLACK),
+            );
+            y -= 1;
+        }
+    }
+
+    // Timed statuses, stacked up above the hunger line
+    if let Some(statuses) = ecs.read_storage::<StatusEffects>().get(*player_entity) {
+        for status in statuses.effects.iter() {
+            let label = format!("{} ({})", status.kind.name(), status.turns);
+            draw_batch.print_color(Point::new(x, y), &label, ColorPair::new(status.kind.color(), BLACK));
+            y -= 1;
         }
     }
 }
@@ -145,6 +229,7 @@ pub fn draw_ui(world: &World) {
     box_framework(&mut gui_batch);
     labels(world, &mut gui_batch);
     draw_stats(world, &mut gui_batch, &player_entity);
+    equipment(world, &mut gui_batch, &player_entity);
     status(world, &mut gui_batch, &player_entity);
 
     gui_batch.target(LAYER_LOG);
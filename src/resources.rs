@@ -6,5 +6,5 @@ pub enum TurnState {
     // Actor States
     AwaitingInput,
     PlayerTurn,
-    MonsterTurn,
+    Ticking,
 }
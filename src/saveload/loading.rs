@@ -42,10 +42,12 @@ pub fn load_game(ecs: &mut World) -> Result<(), BoxedError> {
             ecs, de, d,
             Player, Monster, Item, Consumable, BlocksTile, 
             Point, Glyph, FieldOfView, Name, Description, CombatStats, OtherLevelPosition,
-            WantsToMelee, WantsToPickupItem, WantsToUseItem, WantsToDropItem,
-            InBackpack, Ranged, InflictsDamage, AreaOfEffect, Confusion, ProvidesHealing,
-            Equippable, Equipped, DefenseBonus, MeleePowerBonus, Blood, HungerClock, MagicMapper,
-            Hidden, ProvidesFood, EntryTrigger, EntityMoved, SingleActivation,
+            WantsToMelee, WantsToShoot, WantsToCastSpell, WantsToPickupItem, WantsToUseItem, WantsToDropItem,
+            InBackpack, Ranged, InflictsDamage, AreaOfEffect, InflictsStatus, ProvidesHealing,
+            Equippable, Equipped, DefenseBonus, MeleePowerBonus, Blood, HungerClock, MagicMapper, ProvidesIdentification, ProvidesRemoveCurse, Charges, ProvidesRecharge, ProvidesMana, ManaPool, Spell, TeachesSpell,
+            Teleports, TeleportsToTarget, Blinks, KnocksBack, Pulls, SwapsPlaces, CreatesHazard, Douses,
+            Hidden, ProvidesFood, EntryTrigger, EntityMoved, SingleActivation, MonsterAI, Faction,
+            Initiative, StatusEffects, Attributes, DerivedStats, Experience, AttributeBonus, StatsChanged, AttackCost, TwoHanded, Cursed, MeleeDamage, Resistances, RangedAttack, RangedWeapon, Ammunition, LootTable, LeavesCorpse, Follower,
             ParticleLifetime, SerializationHelper, DMSerializationHelper
         );
     }
@@ -1,24 +1,44 @@
 use crate::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashSet};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// Entities
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+const PLAYER_HP: i32 = 30;
+const PLAYER_MANA: i32 = 10;
+const PLAYER_STARTING_SPELL: &str = "Magic Missile";
+
 pub fn spawn_player(world: &mut World, start_pos: Point) -> Entity {
-    world
+    let hunger = *world.fetch::<HungerConfig>();
+    let player = world
         .create_entity()
         .with(Player {})
         .with(start_pos)
+        .with(Faction::new("Player"))
         .with(Glyph::new(to_cp437('@'), ColorPair::new(YELLOW, BLACK), RenderOrder::Player))
         .with(Name::new("SecBot".to_string()))
         .with(Description::new("Everybody's favorite Bracket Corp SecBot"))
         .with(FieldOfView::new(8))
-        .with(CombatStats::new(30, 30, 2, 5))
+        .with(Initiative::new(NORMAL_SPEED))
+        .with(CombatStats::new(PLAYER_HP, PLAYER_HP, 2, 5))
+        .with(Attributes::default())
+        .with(DerivedStats::new(PLAYER_HP, NORMAL_SPEED))
+        .with(StatsChanged {})
+        .with(Experience::new(1))
         .with(Blood(DARKRED.into()))
-        .with(HungerClock::new(HungerState::WellFed, 20))
+        .with(HungerClock::new(
+            hunger.state_for(hunger.starting_nutrition),
+            hunger.starting_nutrition,
+            hunger.player_rate,
+        ))
+        .with(ManaPool::new(PLAYER_MANA))
         .marked::<SimpleMarker<SerializeMe>>()
-        .build()
+        .build();
+
+    raws::spawn_named_spell(&RAWS.lock(), world, PLAYER_STARTING_SPELL, player);
+
+    player
 }
 
 fn room_table(map_depth: i32) -> MasterTable { raws::get_spawn_table_for_depth(&RAWS.lock(), map_depth) }
@@ -26,7 +46,13 @@ fn room_table(map_depth: i32) -> MasterTable { raws::get_spawn_table_for_depth(&
 const MAX_MONSTERS: i32 = 4;
 
 /// Fills a room with stuff!
-pub fn spawn_room(map: &Map, room: &Rect, map_depth: i32, spawn_list: &mut Vec<(usize, String)>) {
+pub fn spawn_room(
+    map: &Map,
+    room: &Rect,
+    map_depth: i32,
+    spawn_list: &mut Vec<(usize, String)>,
+    spawn_groups: &mut Vec<SpawnGroup>,
+) {
     let mut possible_targets: Vec<usize> = Vec::new();
     {
         // Borrow scope - to keep access to the map separated
@@ -40,13 +66,20 @@ pub fn spawn_room(map: &Map, room: &Rect, map_depth: i32, spawn_list: &mut Vec<(
         }
     }
 
-    spawn_region(&possible_targets, map_depth, spawn_list);
+    spawn_region(map, &possible_targets, map_depth, spawn_list, spawn_groups);
 }
 
 /// Fills a region with stuff!
-pub fn spawn_region(area: &[usize], map_depth: i32, spawn_list: &mut Vec<(usize, String)>) {
+pub fn spawn_region(
+    map: &Map,
+    area: &[usize],
+    map_depth: i32,
+    spawn_list: &mut Vec<(usize, String)>,
+    spawn_groups: &mut Vec<SpawnGroup>,
+) {
     let spawn_table = room_table(map_depth);
-    let mut spawn_points: HashMap<usize, String> = HashMap::new();
+    // Kept in tile order, so packs roll their members and claim tiles the same way every time
+    let mut spawn_points: BTreeMap<usize, String> = BTreeMap::new();
     let mut areas: Vec<usize> = Vec::from(area);
 
     // Scope to keep the borrow checker happy
@@ -70,22 +103,49 @@ pub fn spawn_region(area: &[usize], map_depth: i32, spawn_list: &mut Vec<(usize,
         }
     }
 
+    // Packs fan out from their leader, but only onto tiles of this region nothing else has claimed
+    let region: HashSet<usize> = area.iter().copied().collect();
+    let mut taken: HashSet<usize> = spawn_list
+        .iter()
+        .map(|(idx, _)| *idx)
+        .chain(spawn_groups.iter().flat_map(|group| group.tiles()))
+        .chain(spawn_points.keys().copied())
+        .collect();
+
     // Actually spawn the monsters
-    for spawn in spawn_points.iter() {
-        spawn_list.push((*spawn.0, spawn.1.to_string()));
+    for (idx, name) in spawn_points.iter() {
+        let group = raws::get_spawn_group(&RAWS.lock(), name);
+        let group = match group {
+            Some(group) => group,
+            None => {
+                spawn_list.push((*idx, name.to_string()));
+                continue;
+            }
+        };
+
+        let followers = raws::roll_group_members(&group);
+        let tiles = raws::find_free_tiles_near(map, map.index_to_point2d(*idx), followers.len(), |tile| {
+            !region.contains(&tile) || taken.contains(&tile)
+        });
+
+        let followers: Vec<(usize, String)> =
+            followers.into_iter().zip(tiles).map(|(name, pt)| (map.point2d_to_index(pt), name)).collect();
+        taken.extend(followers.iter().map(|(tile, _)| *tile));
+
+        spawn_groups.push(SpawnGroup { leader: (*idx, group.leader), followers });
     }
 }
 
 /// Spawns a named entity (name in tuple.1) at the location in (tuple.0)
-pub fn spawn_entity(world: &mut World, spawn: &(&usize, &String)) {
+pub fn spawn_entity(world: &mut World, spawn: &(&usize, &String)) -> Option<Entity> {
     let map = world.fetch::<Map>();
     let pt = map.index_to_point2d(*spawn.0);
     std::mem::drop(map);
 
     let spawn_result = spawn_named_entity(world, spawn.1, SpawnType::AtPosition(pt));
-    if spawn_result.is_some() {
-        return;
+    if spawn_result.is_none() {
+        println!("WARNING: We don't know how to spawn [{}]!", spawn.1);
     }
 
-    println!("WARNING: We don't know how to spawn [{}]!", spawn.1);
+    spawn_result
 }
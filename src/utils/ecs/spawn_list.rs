@@ -10,6 +10,7 @@ pub const CLOTHIER: &str = "Clothier";
 pub const ALCHEMIST: &str = "Alchemist";
 pub const BLACKSMITH: &str = "Blacksmith";
 pub const PARISHIONER: &str = "Parishioner";
+pub const TOWN_GUARD: &str = "Town Guard";
 pub const DOCK_WORKER: &str = "Dock Worker";
 pub const SHADY_SALESMAN: &str = "Shady Salesman";
 pub const WANNABE_PIRATE: &str = "Wannabe Pirate";
@@ -33,15 +34,6 @@ pub const ARMOR_STAND: &str = "Armor Stand";
 pub const WATER_TROUGH: &str = "Water Trough";
 pub const CHEMISTRY_SET: &str = "Chemistry Set";
 
-// Effects
-pub const AREA_OF_EFFECT: &str = "area_of_effect";
-pub const SINGLE_ACTIVATION: &str = "single_activation";
-pub const RANGED: &str = "ranged";
-pub const DAMAGE: &str = "damage";
-pub const CONFUSION: &str = "confusion";
-pub const FOOD: &str = "food";
-pub const PROVIDES_HEALING: &str = "provides_healing";
-pub const PROVIDES_MANA: &str = "provides_mana";
-pub const MAGIC_MAPPING: &str = "magic_mapping";
-pub const PARTICLE: &str = "particle";
-pub const PARTICLE_LINE: &str = "particle_line";
+// Unidentified Naming
+pub const SCROLL_NAMING: &str = "scroll";
+pub const POTION_NAMING: &str = "potion";
@@ -13,3 +13,4 @@ pub use macros::*;
 pub use magicnum::*;
 pub use menus::*;
 pub use render::*;
+pub use rng::Dice;
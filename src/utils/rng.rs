@@ -1,6 +1,7 @@
 use bracket_lib::random::RandomNumberGenerator;
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 
 lazy_static! {
     pub static ref RNG: Mutex<RandomNumberGenerator> = Mutex::new(RandomNumberGenerator::new());
@@ -11,3 +12,69 @@ pub fn reseed(seed: u64) { *RNG.lock() = RandomNumberGenerator::seeded(seed); }
 pub fn roll_dice(n: i32, die_type: i32) -> i32 { RNG.lock().roll_dice(n, die_type) }
 
 pub fn range(min: i32, max: i32) -> i32 { RNG.lock().range(min, max) }
+
+/// A dice expression such as `1d8+2` or `2d6-1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dice {
+    pub n_dice: i32,
+    pub die_type: i32,
+    pub bonus: i32,
+}
+
+impl Dice {
+    pub fn new(n_dice: i32, die_type: i32, bonus: i32) -> Self { Self { n_dice, die_type, bonus } }
+
+    /// Parses `NdS`, `NdS+B` or `NdS-B`, where a missing `N` means one die. Returns `None` for anything
+    /// else.
+    pub fn parse(dice: &str) -> Option<Self> {
+        let dice = dice.trim();
+        let (n_dice, rest) = dice.split_once('d')?;
+
+        let (die_type, bonus) = match rest.find(['+', '-']) {
+            Some(split) => {
+                let (die_type, bonus) = rest.split_at(split);
+                let bonus = bonus.strip_prefix('+').unwrap_or(bonus);
+                (die_type, bonus.parse::<i32>().ok()?)
+            }
+            None => (rest, 0),
+        };
+
+        let n_dice = if n_dice.is_empty() { 1 } else { n_dice.parse::<i32>().ok()? };
+        let die_type = die_type.parse::<i32>().ok()?;
+        if n_dice < 1 || die_type < 1 {
+            return None;
+        }
+
+        Some(Self::new(n_dice, die_type, bonus))
+    }
+
+    pub fn roll(&self) -> i32 { roll_dice(self.n_dice, self.die_type) + self.bonus }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plain_dice() {
+        assert_eq!(Dice::parse("1d6"), Some(Dice::new(1, 6, 0)));
+    }
+
+    #[test]
+    fn parses_a_bonus_or_a_penalty() {
+        assert_eq!(Dice::parse("2d4+1"), Some(Dice::new(2, 4, 1)));
+        assert_eq!(Dice::parse("3d8-2"), Some(Dice::new(3, 8, -2)));
+    }
+
+    #[test]
+    fn a_missing_count_is_one_die() {
+        assert_eq!(Dice::parse("d6"), Some(Dice::new(1, 6, 0)));
+    }
+
+    #[test]
+    fn rejects_anything_else() {
+        for bad in ["", "d", "6", "1d", "0d6", "1d0", "xd6", "1d6+", "1d6+x", "1d6x"] {
+            assert_eq!(Dice::parse(bad), None, "{:?} should not parse", bad);
+        }
+    }
+}